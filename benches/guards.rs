@@ -0,0 +1,75 @@
+//! Micro benchmarks of the guarded operations against their unguarded `Vec` counterparts.
+//!
+//! Run with `cargo bench`. Uses a plain `Instant` loop rather than a benchmarking framework to keep the crate free of
+//! dev-dependencies that need a newer toolchain than the MSRV.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use vecmin::VecMin;
+
+const LEN: usize = 1024;
+const ITERS: u32 = 10_000;
+
+fn bench(name: &str, mut f: impl FnMut()) {
+    // Warm up caches and the branch predictor before timing.
+    for _ in 0..ITERS / 10 {
+        f();
+    }
+
+    let start = Instant::now();
+    for _ in 0..ITERS {
+        f();
+    }
+    let per_iter = start.elapsed() / ITERS;
+
+    println!(
+        "{name:<24} {:>10.1?}",
+        per_iter.max(Duration::from_nanos(1))
+    );
+}
+
+fn filled() -> Vec<u64> {
+    (0..LEN as u64).collect()
+}
+
+fn main() {
+    bench("vec pop", || {
+        let mut v = filled();
+        while let Some(x) = v.pop() {
+            black_box(x);
+        }
+    });
+    bench("vecmin pop_to_min", || {
+        let mut v = VecMin::<_, 16>::try_from_vec(filled()).unwrap();
+        while let Some(x) = v.pop_to_min() {
+            black_box(x);
+        }
+    });
+
+    bench("vec swap_remove", || {
+        let mut v = filled();
+        while v.len() > 16 {
+            black_box(v.swap_remove(black_box(0)));
+        }
+    });
+    bench("vecmin swap_remove", || {
+        let mut v = VecMin::<_, 16>::try_from_vec(filled()).unwrap();
+        while let Ok(x) = v.swap_remove(black_box(0)) {
+            black_box(x);
+        }
+    });
+
+    bench("vec drain", || {
+        let mut v = filled();
+        while v.len() > 16 {
+            black_box(v.drain(..8).sum::<u64>());
+        }
+    });
+    bench("vecmin drain", || {
+        let mut v = VecMin::<_, 16>::try_from_vec(filled()).unwrap();
+        while let Ok(drain) = v.drain(..8) {
+            black_box(drain.sum::<u64>());
+        }
+    });
+}
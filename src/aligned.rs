@@ -0,0 +1,411 @@
+//! Implementation of the [`AlignedVecMin`] over-aligned vector.
+
+use alloc::alloc::{self as raw, Layout, handle_alloc_error};
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Formatter};
+use core::marker::PhantomData;
+use core::mem::{ManuallyDrop, align_of, size_of};
+use core::ops::{Deref, DerefMut};
+use core::ptr::{self, NonNull};
+use core::slice;
+
+use crate::ModifyError;
+use crate::vec::{ConstructError, VecMin};
+
+/// A vector with a minimum length of `M` whose buffer starts at an address aligned to `ALIGN` bytes.
+///
+/// `ALIGN` must be a power of two, the buffer is aligned to the larger of `ALIGN` and the alignment of `T`. This lets
+/// the guaranteed prefix from [`AlignedVecMin::min_slice`] be handed to SIMD kernels expecting e.g. 32 or 64 byte
+/// alignment without a runtime fixup. Only the operations needed to build and shrink the vector are provided, read and
+/// in place access is available through `Deref<Target = [T]>`, and [`AlignedVecMin::into_vec_min`] converts into a
+/// regular [`VecMin`] by copying.
+///
+/// Not available with the `vecmin_no_unsafe` cfg, as the buffer is allocated by hand.
+pub struct AlignedVecMin<T, const M: usize, const ALIGN: usize> {
+    ptr: NonNull<T>,
+    len: usize,
+    cap: usize,
+    marker: PhantomData<T>,
+}
+
+// Safety: `AlignedVecMin` owns its elements like a `Vec`.
+unsafe impl<T: Send, const M: usize, const ALIGN: usize> Send for AlignedVecMin<T, M, ALIGN> {}
+// Safety: `AlignedVecMin` owns its elements like a `Vec`.
+unsafe impl<T: Sync, const M: usize, const ALIGN: usize> Sync for AlignedVecMin<T, M, ALIGN> {}
+
+// --- Allocation ---
+impl<T, const M: usize, const ALIGN: usize> AlignedVecMin<T, M, ALIGN> {
+    const BUFFER_ALIGN: usize = {
+        assert!(ALIGN.is_power_of_two(), "alignment must be a power of two");
+
+        if ALIGN > align_of::<T>() {
+            ALIGN
+        } else {
+            align_of::<T>()
+        }
+    };
+
+    /// Returns an empty vector with room for `cap` elements. The length is below `M` until elements are written.
+    fn with_capacity(cap: usize) -> Self {
+        let mut empty = Self {
+            ptr: match NonNull::new(ptr::without_provenance_mut(Self::BUFFER_ALIGN)) {
+                Some(ptr) => ptr,
+                None => unreachable!(),
+            },
+            len: 0,
+            cap: if size_of::<T>() == 0 { usize::MAX } else { 0 },
+            marker: PhantomData,
+        };
+
+        empty.grow_to(cap);
+        empty
+    }
+
+    #[track_caller]
+    fn layout(cap: usize) -> Layout {
+        size_of::<T>()
+            .checked_mul(cap)
+            .and_then(|size| Layout::from_size_align(size, Self::BUFFER_ALIGN).ok())
+            .expect("capacity overflow")
+    }
+
+    /// Grows the capacity to at least `cap`, does nothing if the capacity is already large enough.
+    #[track_caller]
+    fn grow_to(&mut self, cap: usize) {
+        if cap <= self.cap {
+            return;
+        }
+
+        let layout = Self::layout(cap);
+        let ptr = if self.cap == 0 {
+            // Safety: `layout` has a non-zero size, as `T` is not zero sized (otherwise `self.cap` is `usize::MAX`)
+            // and `cap` is greater than 0.
+            unsafe { raw::alloc(layout) }
+        } else {
+            // Safety: The buffer was allocated with the layout for `self.cap`, and the new size is non-zero and was
+            // checked not to overflow by `Self::layout`.
+            unsafe {
+                raw::realloc(
+                    self.ptr.as_ptr().cast(),
+                    Self::layout(self.cap),
+                    layout.size(),
+                )
+            }
+        };
+
+        self.ptr = match NonNull::new(ptr.cast()) {
+            Some(ptr) => ptr,
+            None => handle_alloc_error(layout),
+        };
+        self.cap = cap;
+    }
+}
+
+impl<T, const M: usize, const ALIGN: usize> Drop for AlignedVecMin<T, M, ALIGN> {
+    fn drop(&mut self) {
+        // Safety: The first `len` elements are initialized and owned.
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) };
+
+        if self.cap != 0 && size_of::<T>() != 0 {
+            // Safety: The buffer was allocated with the layout for `self.cap`.
+            unsafe { raw::dealloc(self.ptr.as_ptr().cast(), Self::layout(self.cap)) };
+        }
+    }
+}
+
+// --- Constructors, Convertors, and Destructors ---
+impl<T, const M: usize, const ALIGN: usize> AlignedVecMin<T, M, ALIGN> {
+    /// Creates a new `AlignedVecMin` by moving the elements of a `Vec` into an aligned buffer, returning an error if
+    /// its length is less than `M`.
+    pub fn try_from_vec(vec: Vec<T>) -> Result<Self, ConstructError<T, M>> {
+        if vec.len() < M {
+            return Err(ConstructError(vec));
+        }
+
+        let mut vec = ManuallyDrop::new(vec);
+        let mut aligned = Self::with_capacity(vec.len());
+
+        // Safety: The buffer has room for `vec.len()` elements, and the elements are moved out of `vec` by setting its
+        // length to 0 before it is dropped.
+        unsafe {
+            ptr::copy_nonoverlapping(vec.as_ptr(), aligned.ptr.as_ptr(), vec.len());
+            aligned.len = vec.len();
+            vec.set_len(0);
+            ManuallyDrop::drop(&mut vec);
+        }
+
+        Ok(aligned)
+    }
+
+    /// Creates a new `AlignedVecMin` from an array containing the minimum elements.
+    #[inline]
+    pub fn from_array(array: [T; M]) -> Self {
+        match Self::try_from_vec(array.into()) {
+            Ok(aligned) => aligned,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Moves the elements into a regular [`VecMin`], which does not keep the alignment.
+    pub fn into_vec_min(self) -> VecMin<T, M> {
+        let mut this = ManuallyDrop::new(self);
+        let mut vec = Vec::with_capacity(this.len);
+
+        // Safety: `vec` has room for `len` elements, and they are moved out of `this` by setting its length to 0
+        // before dropping it, which then only frees the buffer.
+        unsafe {
+            ptr::copy_nonoverlapping(this.ptr.as_ptr(), vec.as_mut_ptr(), this.len);
+            vec.set_len(this.len);
+            this.len = 0;
+            ManuallyDrop::drop(&mut this);
+        }
+
+        VecMin::from_inner(vec)
+    }
+}
+
+impl<T, const M: usize, const ALIGN: usize> From<VecMin<T, M>> for AlignedVecMin<T, M, ALIGN> {
+    #[inline]
+    fn from(vec_min: VecMin<T, M>) -> Self {
+        match Self::try_from_vec(vec_min.into_inner()) {
+            Ok(aligned) => aligned,
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+impl<T, const M: usize, const ALIGN: usize> From<AlignedVecMin<T, M, ALIGN>> for VecMin<T, M> {
+    #[inline]
+    fn from(aligned: AlignedVecMin<T, M, ALIGN>) -> Self {
+        aligned.into_vec_min()
+    }
+}
+
+impl<T: Clone, const M: usize, const ALIGN: usize> Clone for AlignedVecMin<T, M, ALIGN> {
+    fn clone(&self) -> Self {
+        let mut clone = Self::with_capacity(self.len);
+        for item in self.as_slice() {
+            clone.push(item.clone());
+        }
+        clone
+    }
+}
+
+impl<T: Debug, const M: usize, const ALIGN: usize> Debug for AlignedVecMin<T, M, ALIGN> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+impl<T: PartialEq, const M: usize, const ALIGN: usize> PartialEq for AlignedVecMin<T, M, ALIGN> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const M: usize, const ALIGN: usize> Eq for AlignedVecMin<T, M, ALIGN> {}
+
+// --- View ---
+impl<T, const M: usize, const ALIGN: usize> AlignedVecMin<T, M, ALIGN> {
+    /// Returns the minimum length of the vector.
+    #[inline]
+    pub const fn minimum(&self) -> usize {
+        M
+    }
+
+    /// Returns the alignment of the buffer in bytes, which is the larger of `ALIGN` and the alignment of `T`.
+    #[inline]
+    pub const fn alignment(&self) -> usize {
+        Self::BUFFER_ALIGN
+    }
+
+    /// See [`Vec::len`].
+    #[inline]
+    #[allow(clippy::len_without_is_empty)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// See [`Vec::capacity`].
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        self.cap
+    }
+
+    /// See [`Vec::as_ptr`]. The pointer is aligned to [`alignment`](AlignedVecMin::alignment).
+    #[inline]
+    pub const fn as_ptr(&self) -> *const T {
+        self.ptr.as_ptr()
+    }
+
+    /// See [`Vec::as_mut_ptr`]. The pointer is aligned to [`alignment`](AlignedVecMin::alignment).
+    #[inline]
+    pub const fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr.as_ptr()
+    }
+
+    /// See [`Vec::as_slice`].
+    #[inline]
+    pub const fn as_slice(&self) -> &[T] {
+        // Safety: The first `len` elements are initialized, and the pointer is non-null and aligned.
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    /// See [`Vec::as_mut_slice`].
+    #[inline]
+    pub const fn as_mut_slice(&mut self) -> &mut [T] {
+        // Safety: The first `len` elements are initialized, and the pointer is non-null and aligned.
+        unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+
+    /// Returns a slice to the first `M` elements of the vector, which are guaranteed to exist and start at an aligned
+    /// address.
+    #[inline]
+    pub const fn min_slice(&self) -> &[T; M] {
+        match self.as_slice().first_chunk() {
+            Some(min) => min,
+            None => unreachable!(),
+        }
+    }
+
+    /// Returns a mutable slice to the first `M` elements of the vector, which are guaranteed to exist and start at an
+    /// aligned address.
+    #[inline]
+    pub const fn min_slice_mut(&mut self) -> &mut [T; M] {
+        match self.as_mut_slice().first_chunk_mut() {
+            Some(min) => min,
+            None => unreachable!(),
+        }
+    }
+}
+
+impl<T, const M: usize, const ALIGN: usize> Deref for AlignedVecMin<T, M, ALIGN> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T, const M: usize, const ALIGN: usize> DerefMut for AlignedVecMin<T, M, ALIGN> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+// --- Mutable Access ---
+
+// -- Not Len Decreasing --
+impl<T, const M: usize, const ALIGN: usize> AlignedVecMin<T, M, ALIGN> {
+    /// See [`Vec::reserve`].
+    #[track_caller]
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.len.checked_add(additional).expect("capacity overflow");
+        if required > self.cap {
+            self.grow_to(required.max(self.cap.saturating_mul(2)).max(4));
+        }
+    }
+
+    /// See [`Vec::push`].
+    #[inline]
+    pub fn push(&mut self, item: T) {
+        self.reserve(1);
+
+        // Safety: `reserve` made room for at least one more element.
+        unsafe { self.ptr.as_ptr().add(self.len).write(item) };
+        self.len += 1;
+    }
+
+    /// See [`Vec::extend_from_slice`].
+    #[inline]
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.reserve(other.len());
+        for item in other {
+            self.push(item.clone());
+        }
+    }
+}
+
+impl<T, const M: usize, const ALIGN: usize> Extend<T> for AlignedVecMin<T, M, ALIGN> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+// -- Len Decreasing --
+impl<T, const M: usize, const ALIGN: usize> AlignedVecMin<T, M, ALIGN> {
+    /// See [`Vec::pop`]. Pops an element from the vector if the length of the vector is greater than `M`, otherwise does nothing and returns `None`.
+    #[inline]
+    pub fn pop_to_min(&mut self) -> Option<T> {
+        if self.len > M {
+            self.len -= 1;
+
+            // Safety: The element at the old last index is initialized and no longer owned by the vector.
+            Some(unsafe { self.ptr.as_ptr().add(self.len).read() })
+        } else {
+            None
+        }
+    }
+
+    /// See [`Vec::truncate`]. Returns an error if the operation would reduce the length of the vector below `M`.
+    #[must_use = "this operation may fail"]
+    pub fn truncate(&mut self, len: usize) -> Result<(), ModifyError<M>> {
+        if len < M {
+            return ModifyError::fail();
+        }
+
+        if len < self.len {
+            // Safety: The elements from `len` are initialized, and the length is reduced before dropping them so a
+            // panicking destructor cannot cause a double drop.
+            unsafe {
+                let tail =
+                    ptr::slice_from_raw_parts_mut(self.ptr.as_ptr().add(len), self.len - len);
+                self.len = len;
+                ptr::drop_in_place(tail);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vecmin;
+
+    #[test]
+    fn alignment() {
+        let mut v = AlignedVecMin::<u8, 2, 64>::from(vecmin![2; [1, 2, 3]]);
+        assert_eq!(v.as_ptr() as usize % 64, 0);
+
+        v.extend(4..=40);
+        assert_eq!(v.as_ptr() as usize % 64, 0);
+        assert_eq!(v.min_slice(), &[1, 2]);
+
+        v.truncate(1).unwrap_err();
+        v.truncate(3).unwrap();
+        assert_eq!(v.pop_to_min(), Some(3));
+        assert_eq!(v.pop_to_min(), None);
+        assert_eq!(v.clone().into_vec_min(), [1, 2].to_vec());
+    }
+
+    #[test]
+    fn zero_sized() {
+        let mut v = AlignedVecMin::<(), 1, 32>::from_array([()]);
+        v.extend([(), ()]);
+        assert_eq!(v.len(), 3);
+        assert_eq!(v.as_ptr() as usize % 32, 0);
+    }
+}
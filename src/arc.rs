@@ -0,0 +1,140 @@
+//! Implementation of the [`ArcVecMin`] copy-on-write newtype.
+
+use alloc::sync::Arc;
+use core::ops::Deref;
+
+use crate::vec::VecMin;
+
+/// A reference counted [`VecMin`] that is cloned on write.
+///
+/// Cloning an `ArcVecMin` is cheap and shares the vector, and reads go through `Deref<Target = VecMin<T, M>>`. Mutation
+/// goes through [`ArcVecMin::make_mut`], which clones the vector first if it is shared and then hands out the guarded
+/// `VecMin` API, so the minimum length is enforced for writers like for any other `VecMin`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArcVecMin<T, const M: usize> {
+    vec: Arc<VecMin<T, M>>,
+}
+
+// --- Constructors, Convertors, and Destructors ---
+impl<T, const M: usize> ArcVecMin<T, M> {
+    /// Moves a `VecMin` into a new shared allocation.
+    #[inline]
+    pub fn new(vec: VecMin<T, M>) -> Self {
+        Self { vec: Arc::new(vec) }
+    }
+
+    /// Returns the inner `VecMin`, cloning it if it is shared.
+    #[inline]
+    pub fn into_inner(self) -> VecMin<T, M>
+    where
+        T: Clone,
+    {
+        Arc::unwrap_or_clone(self.vec)
+    }
+
+    /// Returns the inner `VecMin` if it is not shared, otherwise returns the `ArcVecMin` back as an error.
+    #[inline]
+    pub fn try_into_inner(self) -> Result<VecMin<T, M>, Self> {
+        Arc::try_unwrap(self.vec).map_err(|vec| Self { vec })
+    }
+}
+
+impl<T, const M: usize> Clone for ArcVecMin<T, M> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            vec: Arc::clone(&self.vec),
+        }
+    }
+}
+
+impl<T, const M: usize> From<VecMin<T, M>> for ArcVecMin<T, M> {
+    #[inline]
+    fn from(vec: VecMin<T, M>) -> Self {
+        Self::new(vec)
+    }
+}
+
+impl<T: Clone, const M: usize> From<ArcVecMin<T, M>> for VecMin<T, M> {
+    #[inline]
+    fn from(arc: ArcVecMin<T, M>) -> Self {
+        arc.into_inner()
+    }
+}
+
+// --- View ---
+impl<T, const M: usize> ArcVecMin<T, M> {
+    /// Returns `true` if both share the same vector, see [`Arc::ptr_eq`].
+    #[inline]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.vec, &other.vec)
+    }
+
+    /// Returns the number of `ArcVecMin`s sharing the vector, see [`Arc::strong_count`].
+    #[inline]
+    pub fn share_count(&self) -> usize {
+        Arc::strong_count(&self.vec)
+    }
+}
+
+impl<T, const M: usize> Deref for ArcVecMin<T, M> {
+    type Target = VecMin<T, M>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.vec
+    }
+}
+
+impl<T, const M: usize> AsRef<VecMin<T, M>> for ArcVecMin<T, M> {
+    #[inline]
+    fn as_ref(&self) -> &VecMin<T, M> {
+        &self.vec
+    }
+}
+
+impl<T, const M: usize> AsRef<[T]> for ArcVecMin<T, M> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self.vec.as_slice()
+    }
+}
+
+// --- Mutable Access ---
+impl<T, const M: usize> ArcVecMin<T, M> {
+    /// Returns a mutable reference to the vector, cloning it first if it is shared, see [`Arc::make_mut`].
+    #[inline]
+    pub fn make_mut(&mut self) -> &mut VecMin<T, M>
+    where
+        T: Clone,
+    {
+        Arc::make_mut(&mut self.vec)
+    }
+
+    /// Returns a mutable reference to the vector if it is not shared, see [`Arc::get_mut`].
+    #[inline]
+    pub fn get_mut(&mut self) -> Option<&mut VecMin<T, M>> {
+        Arc::get_mut(&mut self.vec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vecmin;
+
+    #[test]
+    fn copy_on_write() {
+        let mut a = ArcVecMin::new(vecmin![2; [1, 2, 3]]);
+        let b = a.clone();
+        assert!(a.ptr_eq(&b) && a.get_mut().is_none());
+
+        a.make_mut().remove(0).unwrap();
+        a.make_mut().truncate(1).unwrap_err();
+        assert!(!a.ptr_eq(&b));
+        assert_eq!((a.as_slice(), b.as_slice()), (&[2, 3][..], &[1, 2, 3][..]));
+
+        assert_eq!(a.share_count(), 1);
+        assert_eq!(*a.try_into_inner().unwrap(), [2, 3]);
+    }
+}
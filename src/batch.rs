@@ -0,0 +1,162 @@
+//! Implementation of [`EditBatch`], for applying several edits to a [`VecMin`] with a single length check.
+
+use alloc::vec::Vec;
+use core::ops::{Bound, RangeBounds};
+
+use crate::vec::VecMin;
+use crate::{ModifyError, slice_range};
+
+/// A single recorded edit of an [`EditBatch`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Edit<T> {
+    Push(T),
+    Insert(usize, T),
+    Remove(usize),
+    Truncate(usize),
+    Splice((Bound<usize>, Bound<usize>), Vec<T>),
+}
+
+/// A builder recording edits to a [`VecMin`] that are applied all at once by [`EditBatch::apply`].
+///
+/// The length of the vector may temporarily drop below `M` between edits, only the final length is checked.
+/// Indices of each edit refer to the vector as left by the edits recorded before it.
+///
+/// ```
+/// use vecmin::vecmin;
+///
+/// let mut v = vecmin![2; [1, 2, 3]];
+/// v.edit().remove(0).remove(0).push(4).apply().unwrap();
+/// assert_eq!(v, [3, 4].to_vec());
+///
+/// v.edit().truncate(0).push(5).apply().unwrap_err();
+/// assert_eq!(v, [3, 4].to_vec());
+/// ```
+#[derive(Debug)]
+#[must_use = "edits are only applied by `apply`"]
+pub struct EditBatch<'a, T, const M: usize> {
+    vec: &'a mut VecMin<T, M>,
+    edits: Vec<Edit<T>>,
+}
+
+impl<T, const M: usize> VecMin<T, M> {
+    /// Returns an [`EditBatch`] recording edits to this vector.
+    #[inline]
+    pub fn edit(&mut self) -> EditBatch<'_, T, M> {
+        EditBatch {
+            vec: self,
+            edits: Vec::new(),
+        }
+    }
+}
+
+impl<T, const M: usize> EditBatch<'_, T, M> {
+    /// Records a [`Vec::push`].
+    #[inline]
+    pub fn push(mut self, item: T) -> Self {
+        self.edits.push(Edit::Push(item));
+        self
+    }
+
+    /// Records a [`Vec::insert`].
+    #[inline]
+    pub fn insert(mut self, index: usize, element: T) -> Self {
+        self.edits.push(Edit::Insert(index, element));
+        self
+    }
+
+    /// Records a [`Vec::remove`].
+    #[inline]
+    pub fn remove(mut self, index: usize) -> Self {
+        self.edits.push(Edit::Remove(index));
+        self
+    }
+
+    /// Records a [`Vec::truncate`].
+    #[inline]
+    pub fn truncate(mut self, len: usize) -> Self {
+        self.edits.push(Edit::Truncate(len));
+        self
+    }
+
+    /// Records a [`Vec::splice`], replacing `range` with the items of `replace_with`.
+    #[inline]
+    pub fn splice<R, I>(mut self, range: R, replace_with: I) -> Self
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+        self.edits
+            .push(Edit::Splice(range, replace_with.into_iter().collect()));
+        self
+    }
+
+    /// Returns the length the vector would have after applying the recorded edits.
+    ///
+    /// # Panics
+    /// Panics if an edit is out of bounds, in the same way as the corresponding `Vec` method.
+    #[track_caller]
+    pub fn final_len(&self) -> usize {
+        let mut len = self.vec.len();
+
+        for edit in &self.edits {
+            match edit {
+                Edit::Push(_) => len += 1,
+                Edit::Insert(index, _) => {
+                    assert!(
+                        *index <= len,
+                        "insertion index (is {index}) should be <= len (is {len})"
+                    );
+                    len += 1;
+                }
+                Edit::Remove(index) => {
+                    assert!(
+                        *index < len,
+                        "removal index (is {index}) should be < len (is {len})"
+                    );
+                    len -= 1;
+                }
+                Edit::Truncate(new_len) => len = len.min(*new_len),
+                Edit::Splice(range, replace_with) => {
+                    len = len - slice_range(range, ..len).len() + replace_with.len();
+                }
+            }
+        }
+
+        len
+    }
+
+    /// Applies the recorded edits in order, returning an error and leaving the vector untouched if the final length
+    /// would be less than `M`.
+    ///
+    /// Removed elements are only dropped once every edit has been applied, so a panicking destructor cannot leave the
+    /// vector at an intermediate length below `M`.
+    ///
+    /// # Panics
+    /// Panics before applying any edit if an edit is out of bounds.
+    #[track_caller]
+    #[must_use = "this operation may fail"]
+    pub fn apply(self) -> Result<(), ModifyError<M>> {
+        if self.final_len() < M {
+            return ModifyError::fail();
+        }
+
+        // Every edit was bounds checked by `final_len`, so none of these panic part way through.
+        let vec = &mut self.vec.inner;
+        let mut removed = Vec::new();
+        for edit in self.edits {
+            match edit {
+                Edit::Push(item) => vec.push(item),
+                Edit::Insert(index, element) => vec.insert(index, element),
+                Edit::Remove(index) => removed.push(vec.remove(index)),
+                Edit::Truncate(len) => removed.extend(vec.drain(len.min(vec.len())..)),
+                Edit::Splice(range, replace_with) => {
+                    removed.extend(vec.splice(range, replace_with))
+                }
+            }
+        }
+        drop(removed);
+
+        Ok(())
+    }
+}
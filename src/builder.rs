@@ -0,0 +1,122 @@
+//! Implementation of the [`VecMinBuilder`] typestate builder.
+
+use alloc::vec::Vec;
+
+use crate::vec::VecMin;
+
+/// The number of pushes tracked by [`VecMinBuilder`], after which `push` is no longer available.
+pub const MAX_PUSHES: usize = 32;
+
+/// A builder for a [`VecMin`] that counts its elements in the type.
+///
+/// Every `push` returns a builder with `N` increased by one, and [`VecMinBuilder::finish`] fails to compile unless at
+/// least `M` elements have been pushed, so building the vector needs no runtime length check. Stable Rust cannot add
+/// to a const generic, so `push` is implemented for each `N` below [`MAX_PUSHES`].
+///
+/// ```
+/// use vecmin::VecMinBuilder;
+///
+/// let v = VecMinBuilder::<_, 2>::new().push(1).push(2).push(3).finish();
+/// assert_eq!(*v, [1, 2, 3]);
+/// ```
+///
+/// ```compile_fail
+/// use vecmin::VecMinBuilder;
+///
+/// let v = VecMinBuilder::<_, 2>::new().push(1).finish();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VecMinBuilder<T, const M: usize, const N: usize = 0> {
+    vec: Vec<T>,
+}
+
+// --- Constructors, Convertors, and Destructors ---
+impl<T, const M: usize> VecMinBuilder<T, M> {
+    /// Creates a new empty builder.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { vec: Vec::new() }
+    }
+
+    /// Creates a new empty builder with space for at least `capacity` elements.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            vec: Vec::with_capacity(capacity),
+        }
+    }
+}
+
+impl<T, const M: usize> Default for VecMinBuilder<T, M> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const M: usize, const N: usize> VecMinBuilder<T, M, N> {
+    /// Returns the number of elements pushed so far, which is always `N`.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if no elements have been pushed.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Returns the elements pushed so far.
+    #[inline]
+    pub const fn as_slice(&self) -> &[T] {
+        self.vec.as_slice()
+    }
+
+    /// Creates the `VecMin` from the pushed elements.
+    ///
+    /// Fails to compile if fewer than `M` elements have been pushed.
+    #[inline]
+    pub fn finish(self) -> VecMin<T, M> {
+        const { assert!(N >= M, "fewer elements were pushed than the minimum length") };
+
+        // Invariant: `N` elements were pushed, which is at least `M`.
+        VecMin::from_inner(self.vec)
+    }
+}
+
+macro_rules! impl_push {
+    ($n:literal $next:literal $($rest:literal)*) => {
+        impl<T, const M: usize> VecMinBuilder<T, M, $n> {
+            /// Appends an element, returning a builder with one more counted element.
+            #[inline]
+            pub fn push(mut self, item: T) -> VecMinBuilder<T, M, $next> {
+                self.vec.push(item);
+                VecMinBuilder { vec: self.vec }
+            }
+        }
+
+        impl_push!($next $($rest)*);
+    };
+    ($n:literal) => {
+        const _: () = assert!($n == MAX_PUSHES);
+    };
+}
+
+impl_push!(
+    0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder() {
+        let builder = VecMinBuilder::<_, 1>::with_capacity(2).push('a');
+        assert_eq!((builder.len(), builder.as_slice()), (1, &['a'][..]));
+
+        let v = builder.push('b').finish();
+        assert_eq!((v.as_slice(), v.minimum()), (&['a', 'b'][..], 1));
+    }
+}
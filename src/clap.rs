@@ -0,0 +1,70 @@
+//! Helpers for collecting repeated `clap` arguments into a [`VecMin`].
+//!
+//! ```
+//! use clap::{Arg, Command};
+//! use vecmin::clap::{get_many_min, min_values};
+//!
+//! let cmd = Command::new("prog").arg(min_values::<2>(Arg::new("file").short('f')));
+//!
+//! let matches = cmd.clone().get_matches_from(["prog", "-f", "a", "-f", "b"]);
+//! let files = get_many_min::<String, 2>(&matches, "file").unwrap();
+//! assert_eq!(files.min_slice(), &["a", "b"]);
+//!
+//! let matches = cmd.clone().get_matches_from(["prog", "-f", "a"]);
+//! let err = get_many_min::<String, 2>(&matches, "file").unwrap_err().with_cmd(&cmd);
+//! assert_eq!(err.kind(), clap::error::ErrorKind::TooFewValues);
+//! ```
+
+use alloc::string::String;
+use core::any::Any;
+
+use clap::error::{ContextKind, ContextValue, Error, ErrorKind};
+use clap::{Arg, ArgAction, ArgMatches};
+
+use crate::vec::VecMin;
+
+/// Configures `arg` to collect every occurrence of the argument, and makes it required if `M` is greater than 0.
+///
+/// The number of collected values is checked by [`get_many_min`].
+#[inline]
+pub fn min_values<const M: usize>(arg: Arg) -> Arg {
+    arg.action(ArgAction::Append).required(M > 0)
+}
+
+/// Collects the values of the argument `id` into a `VecMin`, returning a [`ErrorKind::TooFewValues`] error if there
+/// are less than `M`.
+///
+/// The error renders like the errors `clap` produces itself, use [`Error::with_cmd`] to add the usage of the command.
+///
+/// # Panics
+/// Panics if `id` is not a valid argument or its values are not of type `T`, like [`ArgMatches::get_many`].
+#[track_caller]
+pub fn get_many_min<T, const M: usize>(
+    matches: &ArgMatches,
+    id: &str,
+) -> Result<VecMin<T, M>, Error>
+where
+    T: Any + Clone + Send + Sync + 'static,
+{
+    let values = matches
+        .get_many::<T>(id)
+        .map(|values| values.cloned().collect())
+        .unwrap_or_default();
+
+    VecMin::try_from_vec(values).map_err(|err| too_few_values(id, err.0.len(), M))
+}
+
+#[cold]
+fn too_few_values(id: &str, actual: usize, min: usize) -> Error {
+    let mut err = Error::new(ErrorKind::TooFewValues);
+    err.insert(
+        ContextKind::InvalidArg,
+        ContextValue::String(String::from(id)),
+    );
+    err.insert(ContextKind::MinValues, ContextValue::Number(min as isize));
+    err.insert(
+        ContextKind::ActualNumValues,
+        ContextValue::Number(actual as isize),
+    );
+    err
+}
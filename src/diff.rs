@@ -0,0 +1,350 @@
+//! Implementation of [`Patch`], a minimal edit script between two [`VecMin`]s.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::ModifyError;
+use crate::vec::VecMin;
+
+/// A single step of a [`Patch`], applied at the current position in the source.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PatchOp<T> {
+    /// Keeps the next `n` elements of the source.
+    Keep(usize),
+    /// Deletes the next `n` elements of the source.
+    Delete(usize),
+    /// Inserts the elements.
+    Insert(Vec<T>),
+}
+
+/// An edit script turning one sequence into another, see [`VecMin::diff`] and [`VecMin::apply_patch`].
+///
+/// The script is minimal in the number of inserted and deleted elements, so only the changed elements need to be sent
+/// to sync a copy of the vector.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Patch<T> {
+    source_len: usize,
+    ops: Vec<PatchOp<T>>,
+}
+
+impl<T> Patch<T> {
+    /// Creates a patch from its steps, for a source of `source_len` elements.
+    ///
+    /// # Panics
+    /// Panics if the steps keep or delete more than `source_len` elements.
+    #[track_caller]
+    pub fn new(source_len: usize, ops: Vec<PatchOp<T>>) -> Self {
+        let consumed = ops
+            .iter()
+            .map(|op| match op {
+                PatchOp::Keep(n) | PatchOp::Delete(n) => *n,
+                PatchOp::Insert(_) => 0,
+            })
+            .fold(0usize, usize::saturating_add);
+        assert!(
+            consumed <= source_len,
+            "patch consumes {consumed} elements of a source of {source_len}"
+        );
+
+        Self { source_len, ops }
+    }
+
+    /// Returns the length of the sequence the patch applies to.
+    #[inline]
+    pub const fn source_len(&self) -> usize {
+        self.source_len
+    }
+
+    /// Returns the length of the sequence after applying the patch.
+    pub fn final_len(&self) -> usize {
+        self.ops.iter().fold(self.source_len, |len, op| match op {
+            PatchOp::Keep(_) => len,
+            PatchOp::Delete(n) => len - n,
+            PatchOp::Insert(items) => len + items.len(),
+        })
+    }
+
+    /// Returns the steps of the patch.
+    #[inline]
+    pub fn ops(&self) -> &[PatchOp<T>] {
+        &self.ops
+    }
+
+    /// Returns `true` if applying the patch changes nothing.
+    #[inline]
+    pub fn is_identity(&self) -> bool {
+        self.ops.iter().all(|op| matches!(op, PatchOp::Keep(_)))
+    }
+
+    /// Returns the steps of the patch, consuming it.
+    #[inline]
+    pub fn into_ops(self) -> Vec<PatchOp<T>> {
+        self.ops
+    }
+
+    fn keep(&mut self, n: usize) {
+        match self.ops.last_mut() {
+            _ if n == 0 => {}
+            Some(PatchOp::Keep(kept)) => *kept += n,
+            _ => self.ops.push(PatchOp::Keep(n)),
+        }
+    }
+
+    fn delete(&mut self, n: usize) {
+        match self.ops.last_mut() {
+            _ if n == 0 => {}
+            Some(PatchOp::Delete(deleted)) => *deleted += n,
+            _ => self.ops.push(PatchOp::Delete(n)),
+        }
+    }
+
+    fn insert(&mut self, item: T) {
+        match self.ops.last_mut() {
+            Some(PatchOp::Insert(items)) => items.push(item),
+            _ => self.ops.push(PatchOp::Insert(vec![item])),
+        }
+    }
+}
+
+impl<T, const M: usize> VecMin<T, M> {
+    /// Returns a minimal [`Patch`] turning this vector into `other`.
+    ///
+    /// Uses the linear space variant of Myers' algorithm, taking `O((n + m) * d)` time and `O(n + m)` memory, where `d`
+    /// is the number of inserted and deleted elements.
+    pub fn diff(&self, other: &[T]) -> Patch<T>
+    where
+        T: PartialEq + Clone,
+    {
+        let (a, b) = (self.as_slice(), other);
+        let mut forward = Diagonals::new(a.len() + b.len());
+        let mut backward = Diagonals::new(a.len() + b.len());
+
+        let mut patch = Patch {
+            source_len: self.len(),
+            ops: Vec::new(),
+        };
+        diff_into(&mut patch, a, b, &mut forward, &mut backward);
+        patch
+    }
+
+    /// Applies a [`Patch`], returning an error and leaving the vector untouched if the final length would be less
+    /// than `M`.
+    ///
+    /// Deleted elements are only dropped once the whole patch has been applied, so a panicking destructor cannot leave
+    /// the vector at an intermediate length below `M`.
+    ///
+    /// # Panics
+    /// Panics if the length of the vector is not the source length of the patch.
+    #[track_caller]
+    #[must_use = "this operation may fail"]
+    pub fn apply_patch(&mut self, patch: Patch<T>) -> Result<(), ModifyError<M>> {
+        assert_eq!(
+            self.len(),
+            patch.source_len,
+            "patch was created for a different length"
+        );
+        if patch.final_len() < M {
+            return ModifyError::fail();
+        }
+
+        // The steps were bounds checked by `Patch::new` or created by `diff`, so none of these panic part way through.
+        let vec = &mut self.inner;
+        let mut removed = Vec::new();
+        let mut index = 0;
+        for op in patch.ops {
+            match op {
+                PatchOp::Keep(n) => index += n,
+                PatchOp::Delete(n) => removed.extend(vec.drain(index..index + n)),
+                PatchOp::Insert(items) => {
+                    let n = items.len();
+                    vec.splice(index..index, items);
+                    index += n;
+                }
+            }
+        }
+        drop(removed);
+
+        Ok(())
+    }
+}
+
+/// The furthest reaching `x` of each diagonal `k = x - y` in Myers' algorithm.
+struct Diagonals {
+    x: Vec<usize>,
+    offset: isize,
+}
+
+impl Diagonals {
+    /// Creates the diagonals for an edit script of at most `max_d` steps.
+    fn new(max_d: usize) -> Self {
+        let offset = max_d / 2 + 2;
+        Self {
+            x: vec![0; 2 * offset + 1],
+            offset: offset as isize,
+        }
+    }
+
+    #[inline]
+    fn get(&self, k: isize) -> usize {
+        self.x[(k + self.offset) as usize]
+    }
+
+    #[inline]
+    fn set(&mut self, k: isize, x: usize) {
+        self.x[(k + self.offset) as usize] = x;
+    }
+}
+
+fn common_prefix<T: PartialEq>(a: &[T], b: &[T]) -> usize {
+    a.iter().zip(b).take_while(|(x, y)| x == y).count()
+}
+
+fn common_suffix<T: PartialEq>(a: &[T], b: &[T]) -> usize {
+    a.iter()
+        .rev()
+        .zip(b.iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count()
+}
+
+/// Appends a minimal edit script turning `a` into `b` to `patch`, splitting both at the middle of the script.
+fn diff_into<T: PartialEq + Clone>(
+    patch: &mut Patch<T>,
+    a: &[T],
+    b: &[T],
+    forward: &mut Diagonals,
+    backward: &mut Diagonals,
+) {
+    let prefix = common_prefix(a, b);
+    patch.keep(prefix);
+    let (a, b) = (&a[prefix..], &b[prefix..]);
+    let suffix = common_suffix(a, b);
+    let (a, b) = (&a[..a.len() - suffix], &b[..b.len() - suffix]);
+
+    let split = if a.is_empty() || b.is_empty() {
+        None
+    } else {
+        middle_snake(a, b, forward, backward)
+    };
+    match split {
+        Some((x, y)) => {
+            diff_into(patch, &a[..x], &b[..y], forward, backward);
+            diff_into(patch, &a[x..], &b[y..], forward, backward);
+        }
+        _ => {
+            patch.delete(a.len());
+            for item in b {
+                patch.insert(item.clone());
+            }
+        }
+    }
+
+    patch.keep(suffix);
+}
+
+/// Returns the point where the forward and backward searches for the shortest edit script first overlap, which splits
+/// it into two scripts of about half the length. `a` and `b` must not have a common prefix or suffix.
+fn middle_snake<T: PartialEq>(
+    a: &[T],
+    b: &[T],
+    forward: &mut Diagonals,
+    backward: &mut Diagonals,
+) -> Option<(usize, usize)> {
+    let (n, m) = (a.len(), b.len());
+    let delta = n as isize - m as isize;
+    let odd = delta & 1 == 1;
+    forward.set(1, 0);
+    backward.set(1, 0);
+
+    for d in 0..((n + m).div_ceil(2) + 1) as isize {
+        for k in (-d..=d).rev().step_by(2) {
+            let mut x = if k == -d || (k != d && forward.get(k - 1) < forward.get(k + 1)) {
+                forward.get(k + 1)
+            } else {
+                forward.get(k - 1) + 1
+            };
+            let y = (x as isize - k) as usize;
+            let start = (x, y);
+            if x < n && y < m {
+                x += common_prefix(&a[x..], &b[y..]);
+            }
+            forward.set(k, x);
+
+            if odd && (k - delta).abs() < d && x + backward.get(delta - k) >= n {
+                return Some(start);
+            }
+        }
+
+        for k in (-d..=d).rev().step_by(2) {
+            let mut x = if k == -d || (k != d && backward.get(k - 1) < backward.get(k + 1)) {
+                backward.get(k + 1)
+            } else {
+                backward.get(k - 1) + 1
+            };
+            let mut y = (x as isize - k) as usize;
+            if x < n && y < m {
+                let common = common_suffix(&a[..n - x], &b[..m - y]);
+                x += common;
+                y += common;
+            }
+            backward.set(k, x);
+
+            if !odd && (k - delta).abs() <= d && x + forward.get(delta - k) >= n {
+                return Some((n - x, m - y));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vecmin;
+
+    #[test]
+    fn diff_and_patch() {
+        let mut a = vecmin![2; [1, 2, 3, 4, 5]];
+        let b = [1, 3, 4, 6, 5];
+
+        let patch = a.diff(&b);
+        assert_eq!(
+            patch.ops(),
+            [
+                PatchOp::Keep(1),
+                PatchOp::Delete(1),
+                PatchOp::Keep(2),
+                PatchOp::Insert([6].to_vec()),
+                PatchOp::Keep(1),
+            ]
+        );
+        a.apply_patch(patch).unwrap();
+        assert_eq!(*a, b);
+
+        assert!(a.diff(&b).is_identity());
+        a.apply_patch(a.diff(&[9])).unwrap_err();
+        assert_eq!(*a, b);
+    }
+
+    #[test]
+    fn diff_long() {
+        let mut a = VecMin::<u32, 1>::try_from((0..1000).collect::<Vec<_>>()).unwrap();
+        let mut b: Vec<u32> = (0..1000).filter(|x| x % 100 != 50).collect();
+        b.insert(500, 5000);
+
+        let patch = a.diff(&b);
+        let changed: usize = patch
+            .ops()
+            .iter()
+            .map(|op| match op {
+                PatchOp::Keep(_) => 0,
+                PatchOp::Delete(n) => *n,
+                PatchOp::Insert(items) => items.len(),
+            })
+            .sum();
+        assert_eq!(changed, 11);
+        a.apply_patch(patch).unwrap();
+        assert_eq!(*a, b);
+    }
+}
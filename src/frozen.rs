@@ -0,0 +1,219 @@
+//! Implementation of the [`FrozenVecMin`] newtype.
+
+use alloc::vec::Vec;
+use core::ops::Deref;
+use core::slice;
+
+use crate::vec::VecMin;
+
+/// A [`VecMin`] whose first `M` elements cannot be modified after construction.
+///
+/// The guaranteed prefix is only reachable through shared references (e.g. [`FrozenVecMin::min_slice`]), while the
+/// surplus after it can be freely mutated, grown and shrunk. Surplus methods take indices relative to the start of the
+/// surplus, so `remove_surplus(0)` removes the element at index `M`.
+#[repr(transparent)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FrozenVecMin<T, const M: usize> {
+    vec: VecMin<T, M>,
+}
+
+// --- Constructors, Convertors, and Destructors ---
+impl<T, const M: usize> FrozenVecMin<T, M> {
+    /// Freezes the first `M` elements of a `VecMin`.
+    #[inline]
+    pub const fn new(vec: VecMin<T, M>) -> Self {
+        Self { vec }
+    }
+
+    /// Returns the inner `VecMin`, consuming the `FrozenVecMin`.
+    #[inline]
+    pub fn into_inner(self) -> VecMin<T, M> {
+        self.vec
+    }
+}
+
+impl<T, const M: usize> From<VecMin<T, M>> for FrozenVecMin<T, M> {
+    #[inline]
+    fn from(vec: VecMin<T, M>) -> Self {
+        Self::new(vec)
+    }
+}
+
+impl<T, const M: usize> From<FrozenVecMin<T, M>> for VecMin<T, M> {
+    #[inline]
+    fn from(frozen: FrozenVecMin<T, M>) -> Self {
+        frozen.vec
+    }
+}
+
+// --- View ---
+impl<T, const M: usize> FrozenVecMin<T, M> {
+    /// Returns the minimum length of the vector.
+    #[inline]
+    pub const fn minimum(&self) -> usize {
+        M
+    }
+
+    /// Returns a slice to the frozen first `M` elements of the vector.
+    #[inline]
+    pub const fn min_slice(&self) -> &[T; M] {
+        self.vec.min_slice()
+    }
+
+    /// Returns a slice to the elements after the frozen prefix.
+    #[inline]
+    pub const fn surplus(&self) -> &[T] {
+        self.vec.split_at_min().1
+    }
+
+    /// Returns a mutable slice to the elements after the frozen prefix.
+    #[inline]
+    pub const fn surplus_mut(&mut self) -> &mut [T] {
+        self.vec.split_at_min_mut().1
+    }
+
+    /// See [`Vec::as_slice`].
+    #[inline]
+    pub const fn as_slice(&self) -> &[T] {
+        self.vec.as_slice()
+    }
+}
+
+impl<T, const M: usize> Deref for FrozenVecMin<T, M> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.vec.as_slice()
+    }
+}
+
+impl<T, const M: usize> AsRef<[T]> for FrozenVecMin<T, M> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self.vec.as_slice()
+    }
+}
+
+// --- Iterators ---
+impl<T, const M: usize> IntoIterator for FrozenVecMin<T, M> {
+    type Item = T;
+    type IntoIter = alloc::vec::IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.into_iter()
+    }
+}
+
+impl<'a, T: 'a, const M: usize> IntoIterator for &'a FrozenVecMin<T, M> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.as_slice().iter()
+    }
+}
+
+// --- Mutable Access ---
+impl<T, const M: usize> FrozenVecMin<T, M> {
+    /// See [`Vec::push`].
+    #[inline]
+    pub fn push(&mut self, item: T) {
+        self.vec.push(item);
+    }
+
+    /// See [`Vec::pop`]. Pops an element from the surplus, or returns `None` if it is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        self.vec.pop_to_min()
+    }
+
+    /// See [`Vec::insert`]. Inserts an element at `index` within the surplus.
+    ///
+    /// # Panics
+    /// Panics if `index` is greater than the length of the surplus.
+    #[inline]
+    #[track_caller]
+    pub fn insert_surplus(&mut self, index: usize, element: T) {
+        assert!(
+            index <= self.surplus().len(),
+            "insertion index (is {index}) should be <= surplus len (is {})",
+            self.surplus().len()
+        );
+        self.vec.insert(M + index, element);
+    }
+
+    /// See [`Vec::remove`]. Removes the element at `index` within the surplus.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds of the surplus.
+    #[inline]
+    #[track_caller]
+    pub fn remove_surplus(&mut self, index: usize) -> T {
+        assert!(
+            index < self.surplus().len(),
+            "removal index (is {index}) should be < surplus len (is {})",
+            self.surplus().len()
+        );
+        match self.vec.remove(M + index) {
+            Ok(element) => element,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// See [`Vec::truncate`]. Truncates the surplus to `len` elements.
+    #[inline]
+    pub fn truncate_surplus(&mut self, len: usize) {
+        self.vec.truncate_or_min(M.saturating_add(len));
+    }
+
+    /// Removes every element of the surplus.
+    #[inline]
+    pub fn clear_surplus(&mut self) {
+        self.vec.truncate_to_min();
+    }
+
+    /// See [`Vec::append`].
+    #[inline]
+    pub fn append(&mut self, other: &mut Vec<T>) {
+        self.vec.append(other);
+    }
+
+    /// See [`Vec::extend_from_slice`].
+    #[inline]
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.vec.extend_from_slice(other);
+    }
+}
+
+impl<T, const M: usize> Extend<T> for FrozenVecMin<T, M> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.vec.extend(iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vecmin;
+
+    #[test]
+    fn surplus() {
+        let mut v = FrozenVecMin::new(vecmin![2; [1, 2, 3]]);
+
+        v.insert_surplus(0, 4);
+        v.surplus_mut()[1] = 5;
+        assert_eq!(v.as_slice(), [1, 2, 4, 5]);
+
+        assert_eq!(v.remove_surplus(1), 5);
+        assert_eq!(v.pop(), Some(4));
+        assert_eq!(v.pop(), None);
+        assert_eq!(v.min_slice(), &[1, 2]);
+    }
+}
@@ -0,0 +1,251 @@
+//! Implementation of the [`Grid2DMin`] rectangular grid.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::ModifyError;
+use crate::vec::VecMin;
+
+/// A rectangular grid with at least `R` rows and at least `C` columns, stored as a [`VecMin`] of rows.
+///
+/// Every row has the same length, so the grid is always rectangular. Methods that remove rows or columns return an
+/// error if the operation would reduce the number of rows below `R` or the number of columns below `C`. The number of
+/// columns is tracked separately from the rows, so a grid without rows (only possible if `R` is 0) keeps its width.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Grid2DMin<T, const R: usize, const C: usize> {
+    rows: VecMin<VecMin<T, C>, R>,
+    cols: usize,
+}
+
+// --- Constructors, Convertors, and Destructors ---
+impl<T, const R: usize, const C: usize> Grid2DMin<T, R, C> {
+    /// Creates a new `Grid2DMin` from rows, returning them back as an error if they are not all the same length.
+    pub fn try_from_rows(rows: VecMin<VecMin<T, C>, R>) -> Result<Self, VecMin<VecMin<T, C>, R>> {
+        let cols = rows.first().map_or(C, |row| row.len());
+
+        if rows.iter().all(|row| row.len() == cols) {
+            Ok(Self { rows, cols })
+        } else {
+            Err(rows)
+        }
+    }
+
+    /// Creates a new `Grid2DMin` of `rows` by `cols` elements cloned from `value`, or `None` if `rows` is less than `R`
+    /// or `cols` is less than `C`.
+    pub fn filled(rows: usize, cols: usize, value: T) -> Option<Self>
+    where
+        T: Clone,
+    {
+        if rows < R || cols < C {
+            return None;
+        }
+
+        // Invariant: Both dimensions were checked to be at least their minimum.
+        let row = VecMin::from_inner(vec![value; cols]);
+        let rows = VecMin::from_inner(vec![row; rows]);
+
+        Some(Self { rows, cols })
+    }
+
+    /// Returns the rows, consuming the `Grid2DMin`.
+    #[inline]
+    pub fn into_rows(self) -> VecMin<VecMin<T, C>, R> {
+        self.rows
+    }
+}
+
+impl<T: Default, const R: usize, const C: usize> Default for Grid2DMin<T, R, C> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            rows: VecMin::default(),
+            cols: C,
+        }
+    }
+}
+
+// --- Immutable Access ---
+impl<T, const R: usize, const C: usize> Grid2DMin<T, R, C> {
+    /// Returns the number of rows.
+    #[inline]
+    pub fn rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns the number of columns.
+    #[inline]
+    pub const fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns a reference to the element at `row` and `col`, or `None` if out of bounds.
+    #[inline]
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.rows.get(row)?.get(col)
+    }
+
+    /// Returns a mutable reference to the element at `row` and `col`, or `None` if out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.rows.get_mut(row)?.get_mut(col)
+    }
+
+    /// Returns the row at `row`, or `None` if out of bounds.
+    #[inline]
+    pub fn row(&self, row: usize) -> Option<&VecMin<T, C>> {
+        self.rows.get(row)
+    }
+
+    /// Returns the elements of the row at `row` mutably, or `None` if out of bounds.
+    #[inline]
+    pub fn row_mut(&mut self, row: usize) -> Option<&mut [T]> {
+        self.rows.get_mut(row).map(|row| row.as_mut_slice())
+    }
+
+    /// Returns an iterator over the rows.
+    #[inline]
+    pub fn iter_rows(&self) -> core::slice::Iter<'_, VecMin<T, C>> {
+        self.rows.iter()
+    }
+
+    /// Returns an iterator over the elements of the column at `col`.
+    ///
+    /// # Panics
+    /// Panics if `col` is out of bounds.
+    #[inline]
+    #[track_caller]
+    pub fn iter_col(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            col < self.cols(),
+            "column index (is {col}) should be < cols (is {})",
+            self.cols()
+        );
+        self.rows.iter().map(move |row| &row[col])
+    }
+}
+
+// --- Mutable Access ---
+impl<T, const R: usize, const C: usize> Grid2DMin<T, R, C> {
+    /// Appends a row to the bottom of the grid.
+    ///
+    /// # Panics
+    /// Panics if the length of `row` is not equal to the number of columns.
+    #[track_caller]
+    pub fn push_row(&mut self, row: VecMin<T, C>) {
+        assert_eq!(
+            row.len(),
+            self.cols(),
+            "row length should equal the number of columns"
+        );
+        self.rows.push(row);
+    }
+
+    /// Appends a column to the right of the grid.
+    ///
+    /// # Panics
+    /// Panics if the length of `col` is not equal to the number of rows.
+    #[track_caller]
+    pub fn push_col(&mut self, col: Vec<T>) {
+        assert_eq!(
+            col.len(),
+            self.rows(),
+            "column length should equal the number of rows"
+        );
+
+        for (row, item) in self.rows.iter_mut().zip(col) {
+            row.push(item);
+        }
+        self.cols += 1;
+    }
+
+    /// Removes the row at `row`. Returns an error if the operation would reduce the number of rows below `R`.
+    ///
+    /// # Panics
+    /// Panics if `row` is out of bounds.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn remove_row(&mut self, row: usize) -> Result<VecMin<T, C>, ModifyError<R>> {
+        self.rows.remove(row)
+    }
+
+    /// Removes the column at `col`. Returns an error if the operation would reduce the number of columns below `C`.
+    ///
+    /// # Panics
+    /// Panics if `col` is out of bounds.
+    #[must_use = "this operation may fail"]
+    pub fn remove_col(&mut self, col: usize) -> Result<Vec<T>, ModifyError<C>> {
+        if self.cols() <= C {
+            return ModifyError::fail();
+        }
+
+        assert!(
+            col < self.cols(),
+            "column index (is {col}) should be < cols (is {})",
+            self.cols()
+        );
+
+        self.cols -= 1;
+        Ok(self
+            .rows
+            .iter_mut()
+            .map(|row| match row.remove(col) {
+                Ok(item) => item,
+                Err(_) => unreachable!(),
+            })
+            .collect())
+    }
+
+    /// Removes the last row if the number of rows is greater than `R`, otherwise does nothing and returns `None`.
+    #[inline]
+    pub fn pop_row_to_min(&mut self) -> Option<VecMin<T, C>> {
+        self.rows.pop_to_min()
+    }
+
+    /// Removes the last column if the number of columns is greater than `C`, otherwise does nothing and returns `None`.
+    pub fn pop_col_to_min(&mut self) -> Option<Vec<T>> {
+        let last = self.cols().checked_sub(1)?;
+        self.remove_col(last).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shape() {
+        assert_eq!(Grid2DMin::<u8, 2, 2>::filled(3, 1, 0), None);
+        let mut grid = Grid2DMin::<u8, 2, 2>::filled(3, 2, 0).unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (3, 2));
+
+        grid.push_col(vec![1, 2, 3]);
+        *grid.get_mut(2, 0).unwrap() = 4;
+        assert_eq!(grid.iter_col(2).copied().collect::<Vec<_>>(), [1, 2, 3]);
+
+        assert_eq!(grid.remove_col(1), Ok(vec![0, 0, 0]));
+        grid.remove_col(0).unwrap_err();
+        assert_eq!(grid.pop_row_to_min().unwrap().as_slice(), [4, 3]);
+        assert_eq!(grid.pop_row_to_min(), None);
+    }
+
+    #[test]
+    fn without_rows() {
+        let mut grid = Grid2DMin::<u8, 0, 1>::filled(0, 2, 0).unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (0, 2));
+
+        grid.push_col(Vec::new());
+        grid.push_row(VecMin::try_from([1, 2, 3].to_vec()).unwrap());
+        assert_eq!((grid.rows(), grid.cols()), (1, 3));
+
+        grid.remove_row(0).unwrap();
+        assert_eq!(grid.pop_col_to_min(), Some(Vec::new()));
+        assert_eq!((grid.rows(), grid.cols()), (0, 2));
+
+        let mut rows = VecMin::default();
+        rows.push(VecMin::try_from([1, 2].to_vec()).unwrap());
+        assert_eq!(
+            Grid2DMin::<u8, 0, 1>::try_from_rows(rows).unwrap().cols(),
+            2
+        );
+    }
+}
@@ -0,0 +1,293 @@
+//! Implementation of the [`GrowthVecMin`] newtype and the [`Growth`] policies controlling its reallocation.
+
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Formatter};
+use core::marker::PhantomData;
+use core::ops::{Deref, RangeBounds};
+
+use crate::vec::VecMin;
+use crate::{ModifyError, slice_range};
+
+/// A policy deciding how much capacity a [`GrowthVecMin`] allocates.
+pub trait Growth {
+    /// The capacity reserved when a [`GrowthVecMin`] is created.
+    const INITIAL_CAPACITY: usize = 0;
+
+    /// Returns the new capacity when `required` elements do not fit into the current `capacity`.
+    ///
+    /// Values less than `required` are raised to `required`.
+    fn grow(capacity: usize, required: usize) -> usize;
+}
+
+/// Doubles the capacity on every reallocation, like `Vec`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Doubling;
+
+impl Growth for Doubling {
+    #[inline]
+    fn grow(capacity: usize, required: usize) -> usize {
+        capacity.saturating_mul(2).max(required)
+    }
+}
+
+/// Grows the capacity by the smallest multiple of `STEP` that fits the required elements, so every reallocation is
+/// bounded by the length plus `STEP`.
+///
+/// Fails to compile if `STEP` is 0.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Linear<const STEP: usize>;
+
+impl<const STEP: usize> Growth for Linear<STEP> {
+    const INITIAL_CAPACITY: usize = STEP;
+
+    #[inline]
+    fn grow(capacity: usize, required: usize) -> usize {
+        const { assert!(STEP > 0, "growth step must be non-zero") };
+
+        let steps = required.saturating_sub(capacity).div_ceil(STEP);
+        capacity.saturating_add(steps.saturating_mul(STEP))
+    }
+}
+
+/// A [`VecMin`] whose growing methods reallocate according to the [`Growth`] policy `G` rather than `Vec`'s doubling.
+///
+/// The inner `VecMin` is only reachable through `Deref`, so every growing method goes through the policy. Elements can
+/// still be modified in place through [`as_mut_slice`](GrowthVecMin::as_mut_slice), and the vector can be taken out
+/// with [`into_inner`](GrowthVecMin::into_inner) to use the rest of the `VecMin` API.
+pub struct GrowthVecMin<T, const M: usize, G: Growth = Doubling> {
+    vec: VecMin<T, M>,
+    growth: PhantomData<G>,
+}
+
+// --- Constructors, Convertors, and Destructors ---
+impl<T, const M: usize, G: Growth> GrowthVecMin<T, M, G> {
+    /// Creates a new `GrowthVecMin`, reserving [`Growth::INITIAL_CAPACITY`] if the vector has less capacity.
+    pub fn new(mut vec: VecMin<T, M>) -> Self {
+        let additional = G::INITIAL_CAPACITY.saturating_sub(vec.len());
+        if vec.capacity() < G::INITIAL_CAPACITY {
+            vec.reserve_exact(additional);
+        }
+
+        Self {
+            vec,
+            growth: PhantomData,
+        }
+    }
+
+    /// Returns the inner `VecMin`, consuming the `GrowthVecMin`.
+    #[inline]
+    pub fn into_inner(self) -> VecMin<T, M> {
+        self.vec
+    }
+}
+
+impl<T, const M: usize, G: Growth> From<VecMin<T, M>> for GrowthVecMin<T, M, G> {
+    #[inline]
+    fn from(vec: VecMin<T, M>) -> Self {
+        Self::new(vec)
+    }
+}
+
+impl<T, const M: usize, G: Growth> From<GrowthVecMin<T, M, G>> for VecMin<T, M> {
+    #[inline]
+    fn from(growth_vec: GrowthVecMin<T, M, G>) -> Self {
+        growth_vec.vec
+    }
+}
+
+impl<T: Clone, const M: usize, G: Growth> Clone for GrowthVecMin<T, M, G> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            vec: self.vec.clone(),
+            growth: PhantomData,
+        }
+    }
+}
+
+impl<T: Debug, const M: usize, G: Growth> Debug for GrowthVecMin<T, M, G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.vec.fmt(f)
+    }
+}
+
+// --- View ---
+impl<T, const M: usize, G: Growth> Deref for GrowthVecMin<T, M, G> {
+    type Target = VecMin<T, M>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.vec
+    }
+}
+
+impl<T, const M: usize, G: Growth> AsRef<VecMin<T, M>> for GrowthVecMin<T, M, G> {
+    #[inline]
+    fn as_ref(&self) -> &VecMin<T, M> {
+        &self.vec
+    }
+}
+
+impl<T, const M: usize, G: Growth> AsMut<[T]> for GrowthVecMin<T, M, G> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+// --- Mutable Access ---
+impl<T, const M: usize, G: Growth> GrowthVecMin<T, M, G> {
+    /// See [`Vec::as_mut_slice`].
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.vec
+    }
+}
+
+// -- Not Len Decreasing --
+impl<T, const M: usize, G: Growth> GrowthVecMin<T, M, G> {
+    /// Reserves capacity for `additional` more elements according to the growth policy.
+    ///
+    /// # Panics
+    /// Panics if the required capacity overflows `usize`.
+    #[track_caller]
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self
+            .vec
+            .len()
+            .checked_add(additional)
+            .expect("capacity overflow");
+
+        if required > self.vec.capacity() {
+            let capacity = G::grow(self.vec.capacity(), required).max(required);
+            self.vec.reserve_exact(capacity - self.vec.len());
+        }
+    }
+
+    /// See [`Vec::push`].
+    #[inline]
+    pub fn push(&mut self, item: T) {
+        self.reserve(1);
+        self.vec.push(item);
+    }
+
+    /// See [`Vec::insert`].
+    #[inline]
+    pub fn insert(&mut self, index: usize, element: T) {
+        self.reserve(1);
+        self.vec.insert(index, element);
+    }
+
+    /// See [`Vec::append`].
+    #[inline]
+    pub fn append(&mut self, other: &mut Vec<T>) {
+        self.reserve(other.len());
+        self.vec.append(other);
+    }
+
+    /// See [`Vec::extend_from_slice`].
+    #[inline]
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.reserve(other.len());
+        self.vec.extend_from_slice(other);
+    }
+
+    /// See [`Vec::extend_from_within`].
+    ///
+    /// # Panics
+    /// Panics if the range is out of bounds.
+    #[track_caller]
+    pub fn extend_from_within<R>(&mut self, range: R)
+    where
+        R: RangeBounds<usize>,
+        T: Clone,
+    {
+        let range = slice_range(&range, ..self.vec.len());
+        self.reserve(range.len());
+        self.vec.extend_from_within(range);
+    }
+}
+
+// -- Len Decreasing --
+impl<T, const M: usize, G: Growth> GrowthVecMin<T, M, G> {
+    /// See [`Vec::pop`]. Pops an element if the length is greater than `M`, otherwise does nothing and returns `None`.
+    #[inline]
+    pub fn pop_to_min(&mut self) -> Option<T> {
+        self.vec.pop_to_min()
+    }
+
+    /// See [`Vec::remove`]. Returns an error if the operation would reduce the length below `M`.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn remove(&mut self, index: usize) -> Result<T, ModifyError<M>> {
+        self.vec.remove(index)
+    }
+
+    /// See [`Vec::swap_remove`]. Returns an error if the operation would reduce the length below `M`.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn swap_remove(&mut self, index: usize) -> Result<T, ModifyError<M>> {
+        self.vec.swap_remove(index)
+    }
+
+    /// See [`Vec::truncate`]. Returns an error if the operation would reduce the length below `M`.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn truncate(&mut self, len: usize) -> Result<(), ModifyError<M>> {
+        self.vec.truncate(len)
+    }
+
+    /// See [`Vec::resize`]. Returns an error if the operation would reduce the length of the vector below `M`.
+    #[must_use = "this operation may fail"]
+    pub fn resize(&mut self, new_len: usize, value: T) -> Result<(), ModifyError<M>>
+    where
+        T: Clone,
+    {
+        self.reserve(new_len.saturating_sub(self.vec.len()));
+        self.vec.resize(new_len, value)
+    }
+}
+
+impl<T, const M: usize, G: Growth> Extend<T> for GrowthVecMin<T, M, G> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vecmin;
+
+    #[test]
+    fn linear() {
+        let mut v = GrowthVecMin::<_, 2, Linear<4>>::new(vecmin![2; [1, 2]]);
+        assert_eq!(v.capacity(), 4);
+
+        v.extend([3, 4, 5]);
+        assert_eq!(v.capacity(), 8);
+
+        v.extend_from_slice(&[6, 7, 8, 9]);
+        assert_eq!(v.capacity(), 12);
+        assert_eq!(v.len(), 9);
+
+        v.extend_from_within(..4);
+        assert_eq!(v.capacity(), 16);
+
+        v.resize(17, 0).unwrap();
+        assert_eq!(v.capacity(), 20);
+        assert_eq!(v.resize(1, 0), Err(ModifyError));
+
+        v.as_mut_slice()[0] = 10;
+        assert_eq!(v.truncate(2), Ok(()));
+        assert_eq!((v.as_slice(), v.capacity()), (&[10, 2][..], 20));
+    }
+}
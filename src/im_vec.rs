@@ -0,0 +1,293 @@
+//! Implementation of the [`ImVecMin`] persistent vector.
+
+use core::ops::Deref;
+
+use im::Vector;
+
+use crate::ModifyError;
+use crate::vec::VecMin;
+
+/// A persistent [`Vector`] with a minimum length of `M`.
+///
+/// Clones share structure with the original, so keeping old versions around (e.g. for undo) is cheap.
+/// Read access is available through `Deref<Target = Vector<T>>`, and methods that reduce the length of the vector
+/// return an error or stop at the minimum like their [`VecMin`] counterparts.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ImVecMin<T: Clone, const M: usize> {
+    vec: Vector<T>,
+}
+
+// --- Constructors, Convertors, and Destructors ---
+impl<T: Clone, const M: usize> ImVecMin<T, M> {
+    /// Creates a new `ImVecMin` from a `Vector`.
+    ///
+    /// # Safety
+    /// - The length of the `Vector` must be at least `M`.
+    #[cfg(not(vecmin_no_unsafe))]
+    #[inline]
+    pub unsafe fn from_vector_unchecked(vec: Vector<T>) -> Self {
+        Self { vec }
+    }
+
+    /// Creates a new `ImVecMin` from a `Vector`.
+    ///
+    /// The `vecmin_no_unsafe` cfg makes this function safe by checking the length.
+    ///
+    /// # Panics
+    /// Panics if the length of the `Vector` is less than `M`.
+    #[cfg(vecmin_no_unsafe)]
+    #[inline]
+    #[track_caller]
+    pub fn from_vector_unchecked(vec: Vector<T>) -> Self {
+        assert!(vec.len() >= M, "length is less than the minimum");
+        Self { vec }
+    }
+
+    /// Creates a new `ImVecMin` from a `Vector`, returning it back as an error if its length is less than `M`.
+    #[inline]
+    pub fn try_from_vector(vec: Vector<T>) -> Result<Self, Vector<T>> {
+        if vec.len() >= M {
+            // Invariant: We just checked that the length was at least `M`.
+            Ok(Self { vec })
+        } else {
+            Err(vec)
+        }
+    }
+
+    /// Returns the inner `Vector`, consuming the `ImVecMin`.
+    #[inline]
+    pub fn into_inner(self) -> Vector<T> {
+        self.vec
+    }
+
+    /// Returns the minimum length of the vector.
+    #[inline]
+    pub const fn minimum(&self) -> usize {
+        M
+    }
+
+    /// Returns `true` if the length of the vector is equal to the minimum length `M`.
+    #[inline]
+    pub fn is_minimum(&self) -> bool {
+        self.vec.len() == M
+    }
+}
+
+impl<T: Clone, const M: usize> From<VecMin<T, M>> for ImVecMin<T, M> {
+    #[inline]
+    fn from(vec_min: VecMin<T, M>) -> Self {
+        // Invariant: The elements come from a valid `VecMin`.
+        Self {
+            vec: vec_min.into_inner().into(),
+        }
+    }
+}
+
+impl<T: Clone, const M: usize> From<ImVecMin<T, M>> for VecMin<T, M> {
+    #[inline]
+    fn from(im_vec_min: ImVecMin<T, M>) -> Self {
+        // Invariant: The elements come from a valid `ImVecMin`.
+        VecMin::from_inner(im_vec_min.vec.into_iter().collect())
+    }
+}
+
+impl<T: Clone, const M: usize> TryFrom<Vector<T>> for ImVecMin<T, M> {
+    type Error = Vector<T>;
+
+    #[inline]
+    fn try_from(vec: Vector<T>) -> Result<Self, Self::Error> {
+        Self::try_from_vector(vec)
+    }
+}
+
+impl<T: Clone, const M: usize> From<ImVecMin<T, M>> for Vector<T> {
+    #[inline]
+    fn from(im_vec_min: ImVecMin<T, M>) -> Self {
+        im_vec_min.vec
+    }
+}
+
+impl<T: Clone + Default, const M: usize> Default for ImVecMin<T, M> {
+    #[inline]
+    fn default() -> Self {
+        VecMin::default().into()
+    }
+}
+
+// --- View ---
+impl<T: Clone, const M: usize> Deref for ImVecMin<T, M> {
+    type Target = Vector<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.vec
+    }
+}
+
+impl<T: Clone, const M: usize> AsRef<Vector<T>> for ImVecMin<T, M> {
+    #[inline]
+    fn as_ref(&self) -> &Vector<T> {
+        &self.vec
+    }
+}
+
+// --- Iterators ---
+impl<T: Clone, const M: usize> IntoIterator for ImVecMin<T, M> {
+    type Item = T;
+    type IntoIter = im::vector::ConsumingIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.into_iter()
+    }
+}
+
+impl<'a, T: Clone, const M: usize> IntoIterator for &'a ImVecMin<T, M> {
+    type Item = &'a T;
+    type IntoIter = im::vector::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.iter()
+    }
+}
+
+// --- Mutable Access ---
+
+// -- Not Len Decreasing --
+impl<T: Clone, const M: usize> ImVecMin<T, M> {
+    /// See [`Vector::get_mut`].
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.vec.get_mut(index)
+    }
+
+    /// See [`Vector::set`].
+    #[inline]
+    pub fn set(&mut self, index: usize, value: T) -> T {
+        self.vec.set(index, value)
+    }
+
+    /// See [`Vector::push_back`].
+    #[inline]
+    pub fn push_back(&mut self, value: T) {
+        self.vec.push_back(value);
+    }
+
+    /// See [`Vector::push_front`].
+    #[inline]
+    pub fn push_front(&mut self, value: T) {
+        self.vec.push_front(value);
+    }
+
+    /// See [`Vector::insert`].
+    #[inline]
+    pub fn insert(&mut self, index: usize, value: T) {
+        self.vec.insert(index, value);
+    }
+
+    /// See [`Vector::append`].
+    #[inline]
+    pub fn append(&mut self, other: Vector<T>) {
+        self.vec.append(other);
+    }
+}
+
+impl<T: Clone, const M: usize> Extend<T> for ImVecMin<T, M> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.vec.extend(iter);
+    }
+}
+
+// -- Len Decreasing --
+impl<T: Clone, const M: usize> ImVecMin<T, M> {
+    /// See [`Vector::pop_back`]. Pops an element if the length of the vector is greater than `M`, otherwise does nothing and returns `None`.
+    #[inline]
+    pub fn pop_back_to_min(&mut self) -> Option<T> {
+        if self.vec.len() > M {
+            self.vec.pop_back()
+        } else {
+            None
+        }
+    }
+
+    /// See [`Vector::pop_front`]. Pops an element if the length of the vector is greater than `M`, otherwise does nothing and returns `None`.
+    #[inline]
+    pub fn pop_front_to_min(&mut self) -> Option<T> {
+        if self.vec.len() > M {
+            self.vec.pop_front()
+        } else {
+            None
+        }
+    }
+
+    /// See [`Vector::remove`]. Returns an error if the operation would reduce the length of the vector below `M`.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn remove(&mut self, index: usize) -> Result<T, ModifyError<M>> {
+        if self.vec.len() > M {
+            Ok(self.vec.remove(index))
+        } else {
+            ModifyError::fail()
+        }
+    }
+
+    /// See [`Vector::truncate`]. Returns an error if the operation would reduce the length of the vector below `M`.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn truncate(&mut self, len: usize) -> Result<(), ModifyError<M>> {
+        if len >= M {
+            self.vec.truncate(len);
+            Ok(())
+        } else {
+            ModifyError::fail()
+        }
+    }
+
+    /// See [`Vector::truncate`]. Truncates the vector to `len` if `len` is greater than or equal to `M`, otherwise truncates the vector to `M`.
+    #[inline]
+    pub fn truncate_or_min(&mut self, len: usize) {
+        self.vec.truncate(len.max(M));
+    }
+
+    /// See [`Vector::truncate`]. Truncates the vector to `M`.
+    #[inline]
+    pub fn truncate_to_min(&mut self) {
+        self.vec.truncate(M);
+    }
+
+    /// See [`Vector::split_off`]. Returns an error if the operation would reduce the length of the vector below `M`.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn split_off(&mut self, at: usize) -> Result<Vector<T>, ModifyError<M>> {
+        if at >= M {
+            Ok(self.vec.split_off(at))
+        } else {
+            ModifyError::fail()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vecmin;
+
+    #[test]
+    fn persistence() {
+        let mut v = ImVecMin::from(vecmin![2; [1, 2, 3]]);
+        let snapshot = v.clone();
+
+        assert_eq!(v.pop_back_to_min(), Some(3));
+        assert_eq!(v.pop_back_to_min(), None);
+        v.remove(0).unwrap_err();
+        v.push_front(0);
+
+        assert_eq!(v.iter().copied().collect::<alloc::vec::Vec<_>>(), [0, 1, 2]);
+        assert_eq!(
+            snapshot.iter().copied().collect::<alloc::vec::Vec<_>>(),
+            [1, 2, 3]
+        );
+    }
+}
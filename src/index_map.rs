@@ -0,0 +1,297 @@
+//! Implementation of the [`IndexMapMin`] newtype.
+
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Formatter};
+use core::hash::{BuildHasher, Hash};
+use core::ops::Deref;
+use std::hash::RandomState;
+
+use indexmap::{Equivalent, IndexMap};
+
+use crate::ModifyError;
+
+/// An [`IndexMap`] with a minimum number of entries `M`.
+///
+/// Read access is available through `Deref<Target = IndexMap<K, V, S>>`. Methods that remove entries return an
+/// error if the operation would reduce the number of entries below `M`.
+#[derive(Clone)]
+pub struct IndexMapMin<K, V, const M: usize, S = RandomState> {
+    map: IndexMap<K, V, S>,
+}
+
+// --- Constructors, Convertors, and Destructors ---
+impl<K, V, const M: usize, S> IndexMapMin<K, V, M, S> {
+    /// Creates a new `IndexMapMin` from an `IndexMap`.
+    ///
+    /// # Safety
+    /// - The length of the `IndexMap` must be at least `M`.
+    #[cfg(not(vecmin_no_unsafe))]
+    #[inline]
+    pub const unsafe fn from_map_unchecked(map: IndexMap<K, V, S>) -> Self {
+        Self { map }
+    }
+
+    /// Creates a new `IndexMapMin` from an `IndexMap`.
+    ///
+    /// The `vecmin_no_unsafe` cfg makes this function safe by checking the length.
+    ///
+    /// # Panics
+    /// Panics if the length of the `IndexMap` is less than `M`.
+    #[cfg(vecmin_no_unsafe)]
+    #[inline]
+    #[track_caller]
+    pub fn from_map_unchecked(map: IndexMap<K, V, S>) -> Self {
+        assert!(map.len() >= M, "length is less than the minimum");
+        Self { map }
+    }
+
+    /// Creates a new `IndexMapMin` from an `IndexMap`, returning it back as an error if its length is less than `M`.
+    #[inline]
+    pub fn try_from_map(map: IndexMap<K, V, S>) -> Result<Self, IndexMap<K, V, S>> {
+        if map.len() >= M {
+            // Invariant: We just checked that the length was at least `M`.
+            Ok(Self { map })
+        } else {
+            Err(map)
+        }
+    }
+
+    /// Returns the inner `IndexMap`, consuming the `IndexMapMin`.
+    #[inline]
+    pub fn into_inner(self) -> IndexMap<K, V, S> {
+        self.map
+    }
+
+    /// Returns the minimum number of entries of the map.
+    #[inline]
+    pub const fn minimum(&self) -> usize {
+        M
+    }
+
+    /// Returns `true` if the number of entries is equal to the minimum `M`.
+    #[inline]
+    pub fn is_minimum(&self) -> bool {
+        self.map.len() == M
+    }
+}
+
+impl<K, V, const M: usize, S> TryFrom<IndexMap<K, V, S>> for IndexMapMin<K, V, M, S> {
+    type Error = IndexMap<K, V, S>;
+
+    #[inline]
+    fn try_from(map: IndexMap<K, V, S>) -> Result<Self, Self::Error> {
+        Self::try_from_map(map)
+    }
+}
+
+impl<K, V, const M: usize, S> From<IndexMapMin<K, V, M, S>> for IndexMap<K, V, S> {
+    #[inline]
+    fn from(map_min: IndexMapMin<K, V, M, S>) -> Self {
+        map_min.map
+    }
+}
+
+// --- View ---
+impl<K, V, const M: usize, S> Deref for IndexMapMin<K, V, M, S> {
+    type Target = IndexMap<K, V, S>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.map
+    }
+}
+
+impl<K: Debug, V: Debug, const M: usize, S> Debug for IndexMapMin<K, V, M, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.map.fmt(f)
+    }
+}
+
+impl<K, V1, V2, const M1: usize, const M2: usize, S1, S2> PartialEq<IndexMapMin<K, V2, M2, S2>>
+    for IndexMapMin<K, V1, M1, S1>
+where
+    K: Hash + Eq,
+    V1: PartialEq<V2>,
+    S1: BuildHasher,
+    S2: BuildHasher,
+{
+    #[inline]
+    fn eq(&self, other: &IndexMapMin<K, V2, M2, S2>) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<K: Hash + Eq, V: Eq, const M: usize, S: BuildHasher> Eq for IndexMapMin<K, V, M, S> {}
+
+// --- Mutable Access ---
+
+// -- Not Len Decreasing --
+impl<K: Hash + Eq, V, const M: usize, S: BuildHasher> IndexMapMin<K, V, M, S> {
+    /// See [`IndexMap::insert`].
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.map.insert(key, value)
+    }
+
+    /// See [`IndexMap::get_mut`].
+    #[inline]
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        self.map.get_mut(key)
+    }
+}
+
+impl<K, V, const M: usize, S> IndexMapMin<K, V, M, S> {
+    /// See [`IndexMap::get_index_mut`].
+    #[inline]
+    pub fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+        self.map.get_index_mut(index)
+    }
+
+    /// See [`IndexMap::values_mut`].
+    #[inline]
+    pub fn values_mut(&mut self) -> indexmap::map::ValuesMut<'_, K, V> {
+        self.map.values_mut()
+    }
+
+    /// See [`IndexMap::iter_mut`].
+    #[inline]
+    pub fn iter_mut(&mut self) -> indexmap::map::IterMut<'_, K, V> {
+        self.map.iter_mut()
+    }
+}
+
+impl<K: Hash + Eq, V, const M: usize, S: BuildHasher> Extend<(K, V)> for IndexMapMin<K, V, M, S> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.map.extend(iter);
+    }
+}
+
+// -- Len Decreasing --
+impl<K: Hash + Eq, V, const M: usize, S: BuildHasher> IndexMapMin<K, V, M, S> {
+    /// See [`IndexMap::shift_remove`]. Returns an error if the operation would reduce the number of entries below `M`.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn shift_remove<Q>(&mut self, key: &Q) -> Result<Option<V>, ModifyError<M>>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        if self.map.len() > M {
+            Ok(self.map.shift_remove(key))
+        } else if self.map.contains_key(key) {
+            ModifyError::fail()
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// See [`IndexMap::swap_remove`]. Returns an error if the operation would reduce the number of entries below `M`.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn swap_remove<Q>(&mut self, key: &Q) -> Result<Option<V>, ModifyError<M>>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        if self.map.len() > M {
+            Ok(self.map.swap_remove(key))
+        } else if self.map.contains_key(key) {
+            ModifyError::fail()
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// See [`IndexMap::retain`]. Returns an error and leaves the map untouched if the operation would reduce the
+    /// number of entries below `M`.
+    ///
+    /// The predicate is called exactly once for every entry, in order.
+    #[must_use = "this operation may fail"]
+    pub fn retain<F>(&mut self, mut keep: F) -> Result<(), ModifyError<M>>
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        let mask: Vec<bool> = self.map.iter_mut().map(|(k, v)| keep(k, v)).collect();
+
+        if mask.iter().filter(|keep| **keep).count() < M {
+            return ModifyError::fail();
+        }
+
+        let mut mask = mask.into_iter();
+        self.map.retain(|_, _| mask.next().unwrap_or(true));
+        Ok(())
+    }
+}
+
+impl<K, V, const M: usize, S> IndexMapMin<K, V, M, S> {
+    /// See [`IndexMap::pop`]. Pops an entry if the number of entries is greater than `M`, otherwise does nothing and returns `None`.
+    #[inline]
+    pub fn pop_to_min(&mut self) -> Option<(K, V)> {
+        if self.map.len() > M {
+            self.map.pop()
+        } else {
+            None
+        }
+    }
+
+    /// See [`IndexMap::shift_remove_index`]. Returns an error if the operation would reduce the number of entries below `M`.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn shift_remove_index(&mut self, index: usize) -> Result<Option<(K, V)>, ModifyError<M>> {
+        if self.map.len() > M {
+            Ok(self.map.shift_remove_index(index))
+        } else if index < self.map.len() {
+            ModifyError::fail()
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// See [`IndexMap::swap_remove_index`]. Returns an error if the operation would reduce the number of entries below `M`.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn swap_remove_index(&mut self, index: usize) -> Result<Option<(K, V)>, ModifyError<M>> {
+        if self.map.len() > M {
+            Ok(self.map.swap_remove_index(index))
+        } else if index < self.map.len() {
+            ModifyError::fail()
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// See [`IndexMap::truncate`]. Returns an error if the operation would reduce the number of entries below `M`.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn truncate(&mut self, len: usize) -> Result<(), ModifyError<M>> {
+        if len >= M {
+            self.map.truncate(len);
+            Ok(())
+        } else {
+            ModifyError::fail()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn removal() {
+        let map = IndexMap::from([("a", 1), ("b", 2), ("c", 3)]);
+        let mut map = IndexMapMin::<_, _, 2>::try_from_map(map).unwrap();
+
+        map.retain(|_, v| *v == 1).unwrap_err();
+        assert_eq!(map.len(), 3);
+
+        map.retain(|_, v| *v != 2).unwrap();
+        assert_eq!(map.keys().copied().collect::<Vec<_>>(), ["a", "c"]);
+
+        map.shift_remove("a").unwrap_err();
+        assert_eq!(map.shift_remove("b"), Ok(None));
+        assert_eq!(map.pop_to_min(), None);
+    }
+}
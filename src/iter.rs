@@ -0,0 +1,332 @@
+//! Iterator types returned by methods of [`VecMin`](crate::VecMin).
+
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Formatter};
+use core::iter::{Enumerate, FusedIterator};
+#[cfg(not(vecmin_no_unsafe))]
+use core::ptr;
+use core::slice;
+
+/// An iterator over non-overlapping `&[T; K]` frames, see [`VecMin::frames`](crate::VecMin::frames).
+#[derive(Clone, Debug)]
+pub struct Frames<'a, T, const K: usize> {
+    pub(crate) chunks: slice::ChunksExact<'a, T>,
+}
+
+impl<'a, T, const K: usize> Frames<'a, T, K> {
+    /// Returns the elements after the last complete frame.
+    #[inline]
+    pub fn remainder(&self) -> &'a [T] {
+        self.chunks.remainder()
+    }
+}
+
+impl<'a, T, const K: usize> Iterator for Frames<'a, T, K> {
+    type Item = &'a [T; K];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chunks.next().map(as_frame)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+}
+
+impl<T, const K: usize> DoubleEndedIterator for Frames<'_, T, K> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.chunks.next_back().map(as_frame)
+    }
+}
+
+impl<T, const K: usize> ExactSizeIterator for Frames<'_, T, K> {}
+
+impl<T, const K: usize> FusedIterator for Frames<'_, T, K> {}
+
+/// An iterator over non-overlapping `&mut [T; K]` frames, see [`VecMin::frames_mut`](crate::VecMin::frames_mut).
+#[derive(Debug)]
+pub struct FramesMut<'a, T, const K: usize> {
+    pub(crate) chunks: slice::ChunksExactMut<'a, T>,
+}
+
+impl<'a, T, const K: usize> FramesMut<'a, T, K> {
+    /// Returns the elements after the last complete frame.
+    #[inline]
+    pub fn into_remainder(self) -> &'a mut [T] {
+        self.chunks.into_remainder()
+    }
+}
+
+impl<'a, T, const K: usize> Iterator for FramesMut<'a, T, K> {
+    type Item = &'a mut [T; K];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chunks.next().map(as_frame_mut)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+}
+
+impl<T, const K: usize> DoubleEndedIterator for FramesMut<'_, T, K> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.chunks.next_back().map(as_frame_mut)
+    }
+}
+
+impl<T, const K: usize> ExactSizeIterator for FramesMut<'_, T, K> {}
+
+impl<T, const K: usize> FusedIterator for FramesMut<'_, T, K> {}
+
+/// An element tagged with whether it is part of the guaranteed first `M` elements, see
+/// [`VecMin::iter_tagged`](crate::VecMin::iter_tagged).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MinElem<T> {
+    /// An element within the first `M` elements.
+    Required(T),
+    /// An element after the first `M` elements.
+    Extra(T),
+}
+
+impl<T> MinElem<T> {
+    /// Returns `true` if the element is within the first `M` elements.
+    #[inline]
+    pub const fn is_required(&self) -> bool {
+        matches!(self, MinElem::Required(_))
+    }
+
+    /// Returns the element, discarding the tag.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        match self {
+            MinElem::Required(item) | MinElem::Extra(item) => item,
+        }
+    }
+
+    #[inline]
+    fn tag<const M: usize>((index, item): (usize, T)) -> Self {
+        if index < M {
+            MinElem::Required(item)
+        } else {
+            MinElem::Extra(item)
+        }
+    }
+}
+
+/// An iterator over tagged references to the elements, see [`VecMin::iter_tagged`](crate::VecMin::iter_tagged).
+#[derive(Clone, Debug)]
+pub struct IterTagged<'a, T, const M: usize> {
+    pub(crate) iter: Enumerate<slice::Iter<'a, T>>,
+}
+
+impl<'a, T, const M: usize> Iterator for IterTagged<'a, T, M> {
+    type Item = MinElem<&'a T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(MinElem::tag::<M>)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T, const M: usize> DoubleEndedIterator for IterTagged<'_, T, M> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(MinElem::tag::<M>)
+    }
+}
+
+impl<T, const M: usize> ExactSizeIterator for IterTagged<'_, T, M> {}
+
+impl<T, const M: usize> FusedIterator for IterTagged<'_, T, M> {}
+
+/// An iterator over tagged mutable references to the elements, see
+/// [`VecMin::iter_tagged_mut`](crate::VecMin::iter_tagged_mut).
+#[derive(Debug)]
+pub struct IterTaggedMut<'a, T, const M: usize> {
+    pub(crate) iter: Enumerate<slice::IterMut<'a, T>>,
+}
+
+impl<'a, T, const M: usize> Iterator for IterTaggedMut<'a, T, M> {
+    type Item = MinElem<&'a mut T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(MinElem::tag::<M>)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T, const M: usize> DoubleEndedIterator for IterTaggedMut<'_, T, M> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(MinElem::tag::<M>)
+    }
+}
+
+impl<T, const M: usize> ExactSizeIterator for IterTaggedMut<'_, T, M> {}
+
+impl<T, const M: usize> FusedIterator for IterTaggedMut<'_, T, M> {}
+
+/// An iterator removing the elements matching a predicate until the length reaches `M`, see
+/// [`VecMin::extract_if_min`](crate::VecMin::extract_if_min).
+pub struct ExtractIfMin<'a, T, F, const M: usize> {
+    pub(crate) vec: &'a mut Vec<T>,
+    /// The length of the vector before any element was removed.
+    pub(crate) old_len: usize,
+    /// The number of elements kept so far, including the ones before the range.
+    pub(crate) kept: usize,
+    /// The number of elements removed so far.
+    pub(crate) removed: usize,
+    /// The end of the range to test, as an index into the original vector.
+    pub(crate) end: usize,
+    pub(crate) filter: F,
+}
+
+// The elements are moved back over the removed ones as they are kept, like `Vec::extract_if`. `Vec` hides everything
+// after the kept elements until the iterator is dropped, which could leave fewer than `M` elements if it is leaked, so
+// while fewer than `M` elements are kept the next untested elements are moved ahead of the gap instead:
+//
+//   kept.max(M) >= M: [kept | gap of `removed` | untested..]
+//   kept < M:         [kept | untested | gap of `removed` | untested..]
+//
+// The first `kept.max(M)` elements are always initialized, so that is the length of the vector while there is a gap.
+impl<T, F, const M: usize> ExtractIfMin<'_, T, F, M> {
+    /// The index of the next element to test in the original vector.
+    #[inline]
+    fn next_index(&self) -> usize {
+        self.kept + self.removed
+    }
+}
+
+impl<T, F, const M: usize> Iterator for ExtractIfMin<'_, T, F, M>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    #[cfg(not(vecmin_no_unsafe))]
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next_index() < self.end && self.old_len - self.removed > M {
+            let base = self.vec.as_mut_ptr();
+            let index = if self.kept < M {
+                self.kept
+            } else {
+                self.next_index()
+            };
+
+            // Safety: `index < old_len`, and the element at `index` is initialized and has not been moved out.
+            if (self.filter)(unsafe { &mut *base.add(index) }) {
+                // Safety: The element is moved out and its slot is either refilled or becomes part of the gap.
+                let item = unsafe { ptr::read(base.add(index)) };
+                if self.kept < M {
+                    // Safety: `old_len - removed > M`, so there is an untested element after the gap to close the
+                    // untested elements before it up to `M`.
+                    unsafe {
+                        ptr::copy(base.add(index + 1), base.add(index), M - 1 - index);
+                        ptr::copy_nonoverlapping(base.add(M + self.removed), base.add(M - 1), 1);
+                    }
+                }
+                self.removed += 1;
+                // Safety: The first `kept.max(M)` elements are initialized.
+                unsafe { self.vec.set_len(self.kept.max(M)) };
+                return Some(item);
+            }
+
+            if self.kept >= M && self.removed > 0 {
+                // Safety: The element moves to the first slot of the gap, which is before it.
+                unsafe { ptr::copy_nonoverlapping(base.add(index), base.add(self.kept), 1) };
+            }
+            self.kept += 1;
+            if self.removed > 0 {
+                // Safety: The first `kept.max(M)` elements are initialized.
+                unsafe { self.vec.set_len(self.kept.max(M)) };
+            }
+        }
+        None
+    }
+
+    #[cfg(vecmin_no_unsafe)]
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next_index() < self.end && self.old_len - self.removed > M {
+            if (self.filter)(&mut self.vec[self.kept]) {
+                self.removed += 1;
+                return Some(self.vec.remove(self.kept));
+            }
+            self.kept += 1;
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let untested = self.end.saturating_sub(self.next_index());
+        (
+            0,
+            Some(untested.min((self.old_len - self.removed).saturating_sub(M))),
+        )
+    }
+}
+
+#[cfg(not(vecmin_no_unsafe))]
+impl<T, F, const M: usize> Drop for ExtractIfMin<'_, T, F, M> {
+    fn drop(&mut self) {
+        if self.removed > 0 {
+            let gap = self.kept.max(M);
+            let base = self.vec.as_mut_ptr();
+            // Safety: The untested elements after the gap are moved back over it, after which the first
+            // `old_len - removed` elements are initialized.
+            unsafe {
+                ptr::copy(
+                    base.add(gap + self.removed),
+                    base.add(gap),
+                    self.old_len - gap - self.removed,
+                );
+                self.vec.set_len(self.old_len - self.removed);
+            }
+        }
+    }
+}
+
+impl<T, F, const M: usize> FusedIterator for ExtractIfMin<'_, T, F, M> where F: FnMut(&mut T) -> bool
+{}
+
+impl<T, F, const M: usize> Debug for ExtractIfMin<'_, T, F, M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExtractIfMin")
+            .field("kept", &self.kept)
+            .field("removed", &self.removed)
+            .finish_non_exhaustive()
+    }
+}
+
+#[inline]
+fn as_frame<T, const K: usize>(chunk: &[T]) -> &[T; K] {
+    match chunk.first_chunk() {
+        Some(frame) => frame,
+        None => unreachable!(),
+    }
+}
+
+#[inline]
+fn as_frame_mut<T, const K: usize>(chunk: &mut [T]) -> &mut [T; K] {
+    match chunk.first_chunk_mut() {
+        Some(frame) => frame,
+        None => unreachable!(),
+    }
+}
@@ -1,10 +1,55 @@
 //! Provides a `VecMin` and `VecOne` newtype wrapper around `Vec` that enforces a minimum length at compile time.
+//!
+//! # `vecmin_no_unsafe`
+//!
+//! Building with `RUSTFLAGS="--cfg vecmin_no_unsafe"` forbids unsafe code in the crate. The `_unchecked` constructors
+//! become safe functions that check the length and panic, and the items that need unsafe code ([`AlignedVecMin`],
+//! [`SliceMin`], [`StrMin`] and [`VecMin::as_mut_vec`]) are removed. Because this changes the public API, it is a
+//! `cfg` set by the final build rather than a Cargo feature that any dependency could enable.
 
 #![no_std]
-
+#![cfg_attr(vecmin_no_unsafe, forbid(unsafe_code))]
+
+#[cfg(not(vecmin_no_unsafe))]
+pub mod aligned;
+#[cfg(target_has_atomic = "ptr")]
+pub mod arc;
+pub mod batch;
+pub mod builder;
+#[cfg(feature = "clap")]
+pub mod clap;
+pub mod diff;
+pub mod frozen;
+pub mod grid;
+pub mod growth;
+#[cfg(feature = "im")]
+pub mod im_vec;
+#[cfg(feature = "indexmap")]
+pub mod index_map;
+pub mod iter;
+pub mod non_empty;
+pub mod pool;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "prost")]
+pub mod prost;
+pub mod rope;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(not(vecmin_no_unsafe))]
+pub mod slice_min;
+pub mod storage;
+#[cfg(not(vecmin_no_unsafe))]
+pub mod str_min;
 pub mod vec;
+#[cfg(all(kani, feature = "verification"))]
+mod verification;
+#[cfg(feature = "zeroize")]
+pub mod zeroizing;
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 #[doc(hidden)]
 pub extern crate alloc as __alloc;
@@ -13,7 +58,54 @@ use core::error::Error;
 use core::fmt::{self, Debug, Display, Formatter};
 use core::ops::{Bound, Range, RangeBounds, RangeTo};
 
+#[cfg(not(vecmin_no_unsafe))]
+pub use aligned::AlignedVecMin;
+#[cfg(target_has_atomic = "ptr")]
+pub use arc::ArcVecMin;
+pub use batch::EditBatch;
+pub use builder::VecMinBuilder;
+pub use frozen::FrozenVecMin;
+pub use grid::Grid2DMin;
+pub use growth::GrowthVecMin;
+#[cfg(feature = "im")]
+pub use im_vec::ImVecMin;
+#[cfg(feature = "indexmap")]
+pub use index_map::IndexMapMin;
+pub use pool::VecMinPool;
+pub use rope::RopeMin;
+#[cfg(not(vecmin_no_unsafe))]
+pub use slice_min::SliceMin;
+pub use storage::{Len, Min, Storage};
+#[cfg(not(vecmin_no_unsafe))]
+pub use str_min::StrMin;
 pub use vec::{VecMin, VecOne};
+/// Generates a struct-of-arrays container with a [`VecMin`] column per field, see the documentation in
+/// `vecmin-derive`.
+///
+/// ```
+/// use vecmin::SoaMin;
+///
+/// #[derive(SoaMin)]
+/// struct Particle {
+///     pos: f32,
+///     vel: f32,
+/// }
+///
+/// let mut particles = ParticleSoaMin::<1>::from_array([Particle { pos: 0.0, vel: 1.0 }]);
+/// particles.push(Particle { pos: 5.0, vel: -1.0 });
+///
+/// for (pos, vel) in particles.pos_mut().iter_mut().zip([1.0, -1.0]) {
+///     *pos += vel;
+/// }
+/// assert_eq!(particles.pos(), [1.0, 4.0]);
+///
+/// assert_eq!(particles.remove(0).unwrap().pos, 1.0);
+/// assert!(particles.remove(0).is_err());
+/// ```
+#[cfg(feature = "derive")]
+pub use vecmin_derive::SoaMin;
+#[cfg(feature = "zeroize")]
+pub use zeroizing::ZeroizingVecMin;
 
 /// An error indicating that an operation would reduce the length of a vector below its minimum required length.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -31,27 +123,45 @@ impl<const M: usize> Display for ModifyError<M> {
 
 impl<const M: usize> Error for ModifyError<M> {}
 
+impl<const M: usize> ModifyError<M> {
+    /// Returns `Err(ModifyError)` from a guard check. Kept cold and out of line so the failure branch is moved away
+    /// from the success path of the calling operation.
+    #[cold]
+    #[inline(never)]
+    pub(crate) fn fail<T>() -> Result<T, Self> {
+        Err(ModifyError)
+    }
+}
+
 #[inline]
 #[track_caller]
 fn slice_range<R>(range: &R, bounds: RangeTo<usize>) -> Range<usize>
 where
     R: RangeBounds<usize>,
 {
-    let len = bounds.end;
+    // Only the bound extraction depends on `R`, the checks are shared by every instantiation.
+    bounds_range(
+        range.start_bound().cloned(),
+        range.end_bound().cloned(),
+        bounds.end,
+    )
+}
 
-    let start = match range.start_bound() {
-        Bound::Included(&start) => start,
-        Bound::Excluded(&start) => start
+#[track_caller]
+fn bounds_range(start: Bound<usize>, end: Bound<usize>, len: usize) -> Range<usize> {
+    let start = match start {
+        Bound::Included(start) => start,
+        Bound::Excluded(start) => start
             .checked_add(1)
             .expect("attempted to index slice from after maximum usize"),
         Bound::Unbounded => 0,
     };
 
-    let end = match range.end_bound() {
-        Bound::Included(&end) => end
+    let end = match end {
+        Bound::Included(end) => end
             .checked_add(1)
             .expect("attempted to index slice up to maximum usize"),
-        Bound::Excluded(&end) => end,
+        Bound::Excluded(end) => end,
         Bound::Unbounded => len,
     };
 
@@ -115,12 +225,12 @@ macro_rules! vecone {
 macro_rules! vecmin {
     ($min:expr; [$x:expr; $n:expr]) => {{
         let _: [(); $n - $min];
-        unsafe { $crate::VecMin::<_, $min>::from_vec_unchecked($crate::__alloc::vec![$x; $n]) }
+        $crate::VecMin::<_, $min>::__from_elem::<{ $n }>($x)
     }};
     ($min:expr; [$($x:expr),+ $(,)?]) => {{
-        const N: usize = <[()]>::len(&[$( { let _ = &$x; () }),+]);
+        const N: usize = <[&str]>::len(&[$(::core::stringify!($x)),+]);
         let _: [(); N - $min];
-        unsafe { $crate::VecMin::<_, $min>::from_vec_unchecked($crate::__alloc::vec![$($x),+]) }
+        $crate::VecMin::<_, $min>::from_larger_array([$($x),+])
     }};
     ($x:expr; $n:expr) => {
         $crate::VecMin::from_array([$x; $n])
@@ -130,6 +240,83 @@ macro_rules! vecmin {
     };
 }
 
+/// Destructures the guaranteed prefix of a [`VecMin`] with slice pattern syntax.
+///
+/// The patterns bind to references into the vector, and a trailing `rest @ ..` binds the slice of every element after
+/// them. Because only the first `M` elements are guaranteed to exist, using more than `M` patterns fails to compile
+/// rather than panicking at runtime.
+///
+/// ```
+/// use vecmin::{let_min, vecmin};
+///
+/// let mut v = vecmin![2; [1, 2, 3, 4]];
+///
+/// let_min!([a, b, rest @ ..] = &v);
+/// assert_eq!((a, b, rest), (&1, &2, &[3, 4][..]));
+///
+/// let_min!([first, ..] = &mut v);
+/// *first = 5;
+/// assert_eq!(v[0], 5);
+/// ```
+///
+/// ```compile_fail
+/// use vecmin::{let_min, vecmin};
+///
+/// let v = vecmin![2; [1, 2, 3, 4]];
+/// let_min!([a, b, c, ..] = &v);
+/// ```
+///
+/// The patterns must end with `..` or `rest @ ..`, as the vector may be longer than the patterns.
+///
+/// ```compile_fail
+/// use vecmin::{let_min, vecmin};
+///
+/// let v = vecmin![2; [1, 2, 3, 4]];
+/// let_min!([a, b] = &v);
+/// ```
+#[macro_export]
+macro_rules! let_min {
+    ([$($pat:tt)*] = &mut $v:expr) => {
+        $crate::__let_min!(unique [] [] [$($pat)*] $v);
+    };
+    ([$($pat:tt)*] = &$v:expr) => {
+        $crate::__let_min!(shared [] [] [$($pat)*] $v);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __let_min {
+    (shared [$(($($done:tt)*))*] [] [$rest:ident @ ..] $v:expr) => {
+        let ([$($($done)*),*], $rest) =
+            $crate::VecMin::split_prefix::<{ 0 $(+ $crate::__let_min!(one $($done)*))* }>(&$v);
+    };
+    (unique [$(($($done:tt)*))*] [] [$rest:ident @ ..] $v:expr) => {
+        let ([$($($done)*),*], $rest) =
+            $crate::VecMin::split_prefix_mut::<{ 0 $(+ $crate::__let_min!(one $($done)*))* }>(&mut $v);
+    };
+    (shared [$(($($done:tt)*))*] [] [..] $v:expr) => {
+        let ([$($($done)*),*], _) =
+            $crate::VecMin::split_prefix::<{ 0 $(+ $crate::__let_min!(one $($done)*))* }>(&$v);
+    };
+    (unique [$(($($done:tt)*))*] [] [..] $v:expr) => {
+        let ([$($($done)*),*], _) =
+            $crate::VecMin::split_prefix_mut::<{ 0 $(+ $crate::__let_min!(one $($done)*))* }>(&mut $v);
+    };
+    ($mode:ident [$($done:tt)*] [$($cur:tt)+] [, $($tail:tt)*] $v:expr) => {
+        $crate::__let_min!($mode [$($done)* ($($cur)+)] [] [$($tail)*] $v)
+    };
+    ($mode:ident [$($done:tt)*] [$($cur:tt)*] [$t:tt $($tail:tt)*] $v:expr) => {
+        $crate::__let_min!($mode [$($done)*] [$($cur)* $t] [$($tail)*] $v)
+    };
+    (one $($pat:tt)*) => {
+        1
+    };
+    ($($unmatched:tt)*) => {
+        ::core::compile_error!("let_min! patterns must end with `..` or `name @ ..`");
+    };
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -182,6 +369,21 @@ mod tests {
         assert_eq!(v.len(), 3);
     }
 
+    #[test]
+    fn let_min() {
+        let mut v = vecmin![2; [1, 2, 3]];
+
+        let_min!([a, _, rest @ ..] = &v);
+        assert_eq!((a, rest), (&1, &[3][..]));
+
+        let_min!([_, b, ..] = &mut v);
+        *b += 1;
+        assert_eq!(v[1], 3);
+
+        let_min!([rest @ ..] = &v);
+        assert_eq!(rest.len(), 3);
+    }
+
     // ---- compile errors ----
     // fn lt_min() {
     //     let v = vecone![];
@@ -0,0 +1,140 @@
+//! Infallible accessors for vectors that are guaranteed to be non-empty.
+//!
+//! Importing [`NonEmpty`] makes `first`, `last`, `first_mut` and `last_mut` on a [`VecMin`] return the element instead
+//! of an `Option`, as the trait methods take precedence over the slice methods reached through `Deref`.
+//!
+//! ```
+//! use vecmin::non_empty::{NonEmpty, NonEmptyVec};
+//! use vecmin::vecone;
+//!
+//! let v: NonEmptyVec<_> = vecone![3, 1, 2];
+//! assert_eq!((v.first(), v.last()), (&3, &2));
+//! assert_eq!((v.min_item(), v.max_item()), (&1, &3));
+//! ```
+
+use crate::vec::{VecMin, VecOne};
+
+/// A `Vec` with at least one element.
+pub type NonEmptyVec<T> = VecOne<T>;
+
+/// Infallible accessors for containers with a minimum length of at least 1.
+///
+/// The methods fail to compile if the minimum length is 0.
+pub trait NonEmpty {
+    /// The type of the elements.
+    type Item;
+
+    /// Returns the first element.
+    fn first(&self) -> &Self::Item;
+
+    /// Returns the last element.
+    fn last(&self) -> &Self::Item;
+
+    /// Returns the first element as a mutable reference.
+    fn first_mut(&mut self) -> &mut Self::Item;
+
+    /// Returns the last element as a mutable reference.
+    fn last_mut(&mut self) -> &mut Self::Item;
+
+    /// Returns the maximum element, the last one if several are equally maximum like [`Iterator::max`].
+    fn max_item(&self) -> &Self::Item
+    where
+        Self::Item: Ord;
+
+    /// Returns the minimum element, the first one if several are equally minimum like [`Iterator::min`].
+    fn min_item(&self) -> &Self::Item
+    where
+        Self::Item: Ord;
+}
+
+impl<T, const M: usize> NonEmpty for VecMin<T, M> {
+    type Item = T;
+
+    #[inline]
+    fn first(&self) -> &T {
+        const { assert!(M >= 1, "`first` requires a minimum length of at least 1") };
+
+        match self.as_slice().first() {
+            Some(first) => first,
+            None => unreachable!(),
+        }
+    }
+
+    #[inline]
+    fn last(&self) -> &T {
+        const { assert!(M >= 1, "`last` requires a minimum length of at least 1") };
+
+        match self.as_slice().last() {
+            Some(last) => last,
+            None => unreachable!(),
+        }
+    }
+
+    #[inline]
+    fn first_mut(&mut self) -> &mut T {
+        const {
+            assert!(
+                M >= 1,
+                "`first_mut` requires a minimum length of at least 1"
+            )
+        };
+
+        match self.as_mut_slice().first_mut() {
+            Some(first) => first,
+            None => unreachable!(),
+        }
+    }
+
+    #[inline]
+    fn last_mut(&mut self) -> &mut T {
+        const { assert!(M >= 1, "`last_mut` requires a minimum length of at least 1") };
+
+        match self.as_mut_slice().last_mut() {
+            Some(last) => last,
+            None => unreachable!(),
+        }
+    }
+
+    #[inline]
+    fn max_item(&self) -> &T
+    where
+        T: Ord,
+    {
+        const { assert!(M >= 1, "`max_item` requires a minimum length of at least 1") };
+
+        match self.iter().max() {
+            Some(max) => max,
+            None => unreachable!(),
+        }
+    }
+
+    #[inline]
+    fn min_item(&self) -> &T
+    where
+        T: Ord,
+    {
+        const { assert!(M >= 1, "`min_item` requires a minimum length of at least 1") };
+
+        match self.iter().min() {
+            Some(min) => min,
+            None => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vecmin;
+
+    #[test]
+    fn accessors() {
+        let mut v = vecmin![2; [1, 3, 2, 3]];
+        *v.first_mut() = 4;
+        *v.last_mut() += 1;
+
+        assert_eq!((v.first(), v.last()), (&4, &4));
+        assert!(core::ptr::eq(v.max_item(), &v[3]));
+        assert_eq!(v.min_item(), &2);
+    }
+}
@@ -0,0 +1,166 @@
+//! Implementation of the [`VecMinPool`] allocation pool.
+
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::fmt::{self, Debug, Formatter};
+use core::ops::{Deref, DerefMut};
+
+use crate::vec::VecMin;
+
+/// A pool of recycled allocations for [`VecMin`]s that start out as copies of a template.
+///
+/// [`VecMinPool::get`] hands out a [`PooledVecMin`] guard backed by a previously returned allocation if there is one, and
+/// the guard gives its allocation back to the pool when dropped. Every handed out vector is refilled from the template,
+/// so the minimum length is re-established regardless of what the previous user left in it.
+///
+/// The pool uses a `RefCell` internally and is therefore not `Sync`, use one pool per thread.
+pub struct VecMinPool<T, const M: usize> {
+    template: VecMin<T, M>,
+    free: RefCell<Vec<Vec<T>>>,
+}
+
+/// A `VecMin` borrowed from a [`VecMinPool`], which gives its allocation back to the pool when dropped.
+pub struct PooledVecMin<'a, T: Clone, const M: usize> {
+    vec: Option<VecMin<T, M>>,
+    pool: &'a VecMinPool<T, M>,
+}
+
+// --- Constructors, Convertors, and Destructors ---
+impl<T: Clone, const M: usize> VecMinPool<T, M> {
+    /// Creates an empty pool handing out copies of `template`.
+    #[inline]
+    pub const fn new(template: VecMin<T, M>) -> Self {
+        Self {
+            template,
+            free: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Creates an empty pool handing out vectors of `M` elements, the element at each index being the result of `fill`.
+    #[inline]
+    pub fn from_fn(fill: impl FnMut(usize) -> T) -> Self {
+        Self::new(VecMin::from_array(core::array::from_fn(fill)))
+    }
+
+    /// Returns the template that handed out vectors are copies of.
+    #[inline]
+    pub const fn template(&self) -> &VecMin<T, M> {
+        &self.template
+    }
+
+    /// Returns the number of allocations waiting to be reused.
+    #[inline]
+    pub fn free_count(&self) -> usize {
+        self.free.borrow().len()
+    }
+
+    /// Returns a copy of the template, reusing a free allocation if there is one.
+    pub fn get(&self) -> PooledVecMin<'_, T, M> {
+        let mut vec = self.free.borrow_mut().pop().unwrap_or_default();
+        vec.extend_from_slice(self.template.as_slice());
+
+        PooledVecMin {
+            // Invariant: The template has a length of at least `M`.
+            vec: Some(VecMin::from_inner(vec)),
+            pool: self,
+        }
+    }
+
+    /// Gives the allocation of `vec` to the pool, dropping its elements.
+    pub fn recycle(&self, vec: VecMin<T, M>) {
+        let mut vec = vec.into_inner();
+        vec.clear();
+        if vec.capacity() > 0 {
+            self.free.borrow_mut().push(vec);
+        }
+    }
+
+    /// Drops every free allocation.
+    #[inline]
+    pub fn clear(&self) {
+        self.free.borrow_mut().clear();
+    }
+}
+
+impl<T: Debug, const M: usize> Debug for VecMinPool<T, M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VecMinPool")
+            .field("template", &self.template)
+            .field("free", &self.free.borrow().len())
+            .finish()
+    }
+}
+
+impl<T: Clone, const M: usize> PooledVecMin<'_, T, M> {
+    /// Detaches the `VecMin` from the pool, so its allocation is not given back when it is dropped.
+    #[inline]
+    pub fn into_inner(mut self) -> VecMin<T, M> {
+        match self.vec.take() {
+            Some(vec) => vec,
+            None => unreachable!(),
+        }
+    }
+}
+
+impl<T: Clone, const M: usize> Drop for PooledVecMin<'_, T, M> {
+    fn drop(&mut self) {
+        if let Some(vec) = self.vec.take() {
+            self.pool.recycle(vec);
+        }
+    }
+}
+
+// --- View ---
+impl<T: Clone, const M: usize> Deref for PooledVecMin<'_, T, M> {
+    type Target = VecMin<T, M>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        match &self.vec {
+            Some(vec) => vec,
+            None => unreachable!(),
+        }
+    }
+}
+
+impl<T: Clone, const M: usize> DerefMut for PooledVecMin<'_, T, M> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match &mut self.vec {
+            Some(vec) => vec,
+            None => unreachable!(),
+        }
+    }
+}
+
+impl<T: Clone + Debug, const M: usize> Debug for PooledVecMin<'_, T, M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recycle() {
+        let pool = VecMinPool::<u8, 2>::from_fn(|i| i as u8);
+
+        let mut a = pool.get();
+        a.extend_from_slice(&[2, 3, 4]);
+        let ptr = a.as_ptr();
+        drop(a);
+        assert_eq!(pool.free_count(), 1);
+
+        let b = pool.get();
+        assert_eq!((b.as_slice(), b.as_ptr()), (&[0, 1][..], ptr));
+        assert_eq!(pool.free_count(), 0);
+
+        let b = b.into_inner();
+        drop(pool.get());
+        assert_eq!(pool.free_count(), 1);
+        pool.recycle(b);
+        assert_eq!(pool.free_count(), 2);
+    }
+}
@@ -0,0 +1,70 @@
+//! `proptest` strategies generating [`VecMin`]s.
+//!
+//! ```
+//! use proptest::prelude::*;
+//! use vecmin::VecMin;
+//!
+//! proptest! {
+//!     fn first_exists(v in any::<VecMin<u8, 1>>()) {
+//!         prop_assert_eq!(v.first(), v.get(0));
+//!     }
+//! }
+//! # first_exists();
+//! ```
+
+use alloc::vec::Vec;
+
+use proptest::arbitrary::{Arbitrary, any_with};
+use proptest::collection::{self, SizeRange, VecStrategy};
+use proptest::strategy::{Map, Strategy};
+
+use crate::vec::VecMin;
+
+/// The strategy returned by [`vec_min`].
+pub type VecMinStrategy<S, const M: usize> =
+    Map<VecStrategy<S>, fn(Vec<<S as Strategy>::Value>) -> VecMin<<S as Strategy>::Value, M>>;
+
+/// Creates a strategy generating `VecMin`s with elements drawn from `element` and a length in `size`, with the bounds
+/// of `size` raised to `M` if they are less.
+///
+/// Shrinking removes and simplifies elements, but never reduces the length below `M`.
+pub fn vec_min<S: Strategy, const M: usize>(
+    element: S,
+    size: impl Into<SizeRange>,
+) -> VecMinStrategy<S, M> {
+    let (start, end) = size.into().start_end_incl();
+    // Invariant: The generated `Vec`s are never shorter than the start of the size range, even when shrunk.
+    collection::vec(element, start.max(M)..=end.max(M)).prop_map(VecMin::from_inner as fn(_) -> _)
+}
+
+impl<T: Arbitrary, const M: usize> Arbitrary for VecMin<T, M> {
+    type Parameters = (SizeRange, T::Parameters);
+    type Strategy = VecMinStrategy<T::Strategy, M>;
+
+    /// Generates `VecMin`s like `Vec`s, see [`vec_min`].
+    #[inline]
+    fn arbitrary_with((size, params): Self::Parameters) -> Self::Strategy {
+        vec_min(any_with::<T>(params), size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use proptest::test_runner::{TestError, TestRunner};
+
+    use super::*;
+
+    #[test]
+    fn shrinks_to_min() {
+        let mut runner = TestRunner::deterministic();
+        let result = runner.run(&vec_min::<_, 2>(any::<u8>(), 0..8), |_| {
+            Err(TestCaseError::fail("always fails"))
+        });
+
+        match result {
+            Err(TestError::Fail(_, v)) => assert_eq!(*v, [0, 0]),
+            _ => panic!("expected a failure"),
+        }
+    }
+}
@@ -0,0 +1,166 @@
+//! Helpers for validating protobuf `repeated` fields, which `prost` always generates as `Vec<T>`.
+//!
+//! ```
+//! use vecmin::{VecOne, validate_min};
+//! use vecmin::prost::FieldError;
+//!
+//! struct Request {
+//!     ids: Vec<u64>,
+//! }
+//!
+//! fn ids(request: Request) -> Result<VecOne<u64>, FieldError> {
+//!     validate_min!(request.ids)
+//! }
+//!
+//! assert!(ids(Request { ids: vec![1] }).is_ok());
+//!
+//! let err = ids(Request { ids: vec![] }).unwrap_err();
+//! assert_eq!((err.message, err.field), ("Request", "ids"));
+//! ```
+//!
+//! A field can also be converted with `TryFrom` through a [`RepeatedField`], and a `VecMin` converts back into the
+//! `Vec` of an outgoing message with `Into`.
+//!
+//! ```
+//! use vecmin::VecOne;
+//! use vecmin::prost::RepeatedField;
+//!
+//! let ids = VecOne::try_from(RepeatedField::new("Request", "ids", vec![1u64])).unwrap();
+//! let values: Vec<u64> = ids.into();
+//! assert_eq!(values, [1]);
+//! ```
+
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+use crate::vec::VecMin;
+
+/// An error indicating that a `repeated` field has fewer elements than its required minimum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FieldError {
+    /// The name of the message type containing the field.
+    pub message: &'static str,
+    /// The name of the field.
+    pub field: &'static str,
+    /// The number of elements the field contained.
+    pub len: usize,
+    /// The minimum number of elements the field requires.
+    pub minimum: usize,
+}
+
+impl Display for FieldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "field {}.{} has {} elements but requires at least {}",
+            self.message, self.field, self.len, self.minimum
+        )
+    }
+}
+
+impl Error for FieldError {}
+
+/// The values of a `repeated` field along with the names of the field and its message type, which converts into a
+/// [`VecMin`] with `TryFrom`, returning a [`FieldError`] if there are fewer than `M` values.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RepeatedField<T> {
+    /// The name of the message type containing the field.
+    pub message: &'static str,
+    /// The name of the field.
+    pub field: &'static str,
+    /// The values of the field.
+    pub values: Vec<T>,
+}
+
+impl<T> RepeatedField<T> {
+    /// Creates a new `RepeatedField` from the values of the field `field` in the message type `message`.
+    #[inline]
+    pub const fn new(message: &'static str, field: &'static str, values: Vec<T>) -> Self {
+        Self {
+            message,
+            field,
+            values,
+        }
+    }
+}
+
+impl<T, const M: usize> TryFrom<RepeatedField<T>> for VecMin<T, M> {
+    type Error = FieldError;
+
+    #[inline]
+    fn try_from(repeated: RepeatedField<T>) -> Result<Self, Self::Error> {
+        let len = repeated.values.len();
+
+        VecMin::try_from_vec(repeated.values).map_err(|_| FieldError {
+            message: repeated.message,
+            field: repeated.field,
+            len,
+            minimum: M,
+        })
+    }
+}
+
+/// Converts the values of a `repeated` field into a [`VecMin`], returning a [`FieldError`] naming the field if there
+/// are fewer than `M` values.
+#[inline]
+pub fn validate_min<T, const M: usize>(
+    message: &'static str,
+    field: &'static str,
+    values: Vec<T>,
+) -> Result<VecMin<T, M>, FieldError> {
+    VecMin::try_from(RepeatedField::new(message, field, values))
+}
+
+/// Returns the name of a type without its module path, keeping any generic arguments as they are.
+#[doc(hidden)]
+pub fn __short_type_name(name: &'static str) -> &'static str {
+    let end = name.find('<').unwrap_or(name.len());
+
+    match name[..end].rfind("::") {
+        Some(start) => &name[start + 2..],
+        None => name,
+    }
+}
+
+/// Moves a `repeated` field out of a message and validates it with [`validate_min`](crate::prost::validate_min),
+/// naming the message type (without its module path) and field in the error.
+///
+/// The minimum length is inferred from the expected [`VecMin`] type.
+#[macro_export]
+macro_rules! validate_min {
+    ($message:ident . $field:ident) => {
+        $crate::prost::validate_min(
+            $crate::prost::__short_type_name(::core::any::type_name_of_val(&$message)),
+            ::core::stringify!($field),
+            $message.$field,
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn short_type_name() {
+        assert_eq!(__short_type_name("a::b::Request"), "Request");
+        assert_eq!(__short_type_name("Request"), "Request");
+        assert_eq!(
+            __short_type_name("a::Wrapper<b::Request>"),
+            "Wrapper<b::Request>"
+        );
+    }
+
+    #[test]
+    fn repeated_field() {
+        let err = VecMin::<u8, 2>::try_from(RepeatedField::new("Request", "ids", [1].to_vec()))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "field Request.ids has 1 elements but requires at least 2"
+        );
+    }
+}
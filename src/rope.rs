@@ -0,0 +1,301 @@
+//! Implementation of the [`RopeMin`] chunked sequence.
+
+use alloc::vec::Vec;
+use core::iter::Flatten;
+use core::slice;
+
+use crate::ModifyError;
+use crate::vec::{VecMin, VecOne};
+
+/// The length at which a chunk is split in two.
+const MAX_CHUNK: usize = 128;
+/// The length below which a chunk is merged into a neighbour that has room for it.
+const MIN_CHUNK: usize = MAX_CHUNK / 4;
+
+/// A sequence with a minimum length of `M`, stored as a list of non-empty chunks.
+///
+/// Inserting or removing an element moves the elements of its chunk and updates the running length of every chunk
+/// after it, so edits are still linear in the length, `O(n / 128 + 128)`, but move a fraction of what a `Vec` does.
+/// Finding the chunk of an index is a binary search, so indexing is `O(log n)`. Chunks that shrink below a quarter of
+/// the maximum size are merged into a neighbour with room for them, so removals do not leave many small chunks behind.
+/// Methods that reduce the length return an error if the operation would reduce the length below `M`.
+#[derive(Clone, Debug)]
+pub struct RopeMin<T, const M: usize> {
+    chunks: Vec<VecOne<T>>,
+    /// `ends[i]` is the total length of `chunks[..=i]`.
+    ends: Vec<usize>,
+}
+
+// --- Constructors, Convertors, and Destructors ---
+impl<T, const M: usize> RopeMin<T, M> {
+    /// Creates a new `RopeMin` from the elements of a `VecMin`.
+    pub fn new(vec: VecMin<T, M>) -> Self {
+        let mut rope = Self {
+            chunks: Vec::new(),
+            ends: Vec::new(),
+        };
+        let mut vec = vec.into_inner();
+        while !vec.is_empty() {
+            let rest = vec.split_off(vec.len().min(MAX_CHUNK));
+            rope.push_chunk(vec);
+            vec = rest;
+        }
+        rope
+    }
+
+    /// Collects the elements into a `VecMin`, consuming the `RopeMin`.
+    pub fn into_vec_min(self) -> VecMin<T, M> {
+        let mut vec = Vec::with_capacity(self.len());
+        for chunk in self.chunks {
+            vec.extend(chunk);
+        }
+        // Invariant: The rope has a length of at least `M`.
+        VecMin::from_inner(vec)
+    }
+
+    fn push_chunk(&mut self, chunk: Vec<T>) {
+        self.ends.push(self.len() + chunk.len());
+        // Invariant: Callers only push non-empty chunks.
+        self.chunks.push(VecOne::from_inner(chunk));
+    }
+}
+
+impl<T, const M: usize> From<VecMin<T, M>> for RopeMin<T, M> {
+    #[inline]
+    fn from(vec: VecMin<T, M>) -> Self {
+        Self::new(vec)
+    }
+}
+
+impl<T, const M: usize> From<RopeMin<T, M>> for VecMin<T, M> {
+    #[inline]
+    fn from(rope: RopeMin<T, M>) -> Self {
+        rope.into_vec_min()
+    }
+}
+
+// --- View ---
+impl<T, const M: usize> RopeMin<T, M> {
+    /// Returns the minimum length of the sequence.
+    #[inline]
+    pub const fn minimum(&self) -> usize {
+        M
+    }
+
+    /// Returns the number of elements in the sequence.
+    #[inline]
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.ends.last().copied().unwrap_or(0)
+    }
+
+    /// Returns the chunks the elements are stored in, each of which is non-empty.
+    #[inline]
+    pub fn chunks(&self) -> &[VecOne<T>] {
+        &self.chunks
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let (chunk, offset) = self.locate(index)?;
+        Some(&self.chunks[chunk][offset])
+    }
+
+    /// Returns a mutable reference to the element at `index`, or `None` if it is out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let (chunk, offset) = self.locate(index)?;
+        Some(&mut self.chunks[chunk][offset])
+    }
+
+    /// Returns an iterator over the elements.
+    #[inline]
+    pub fn iter(&self) -> Flatten<slice::Iter<'_, VecOne<T>>> {
+        self.chunks.iter().flatten()
+    }
+
+    /// Returns the chunk containing `index` and the offset of `index` within it.
+    fn locate(&self, index: usize) -> Option<(usize, usize)> {
+        let chunk = self.ends.partition_point(|&end| end <= index);
+        let start = match chunk.checked_sub(1) {
+            Some(prev) => self.ends[prev],
+            None => 0,
+        };
+        (chunk < self.chunks.len()).then_some((chunk, index - start))
+    }
+}
+
+impl<'a, T, const M: usize> IntoIterator for &'a RopeMin<T, M> {
+    type Item = &'a T;
+    type IntoIter = Flatten<slice::Iter<'a, VecOne<T>>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+// --- Equality ---
+impl<T: PartialEq, const M: usize> PartialEq for RopeMin<T, M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const M: usize> Eq for RopeMin<T, M> {}
+
+// --- Mutable Access ---
+
+// -- Not Len Decreasing --
+impl<T, const M: usize> RopeMin<T, M> {
+    /// See [`Vec::push`].
+    #[inline]
+    pub fn push(&mut self, item: T) {
+        self.insert(self.len(), item);
+    }
+
+    /// See [`Vec::insert`].
+    ///
+    /// # Panics
+    /// Panics if `index` is greater than the length.
+    #[track_caller]
+    pub fn insert(&mut self, index: usize, element: T) {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "insertion index (is {index}) should be <= len (is {len})"
+        );
+
+        if self.chunks.is_empty() {
+            self.push_chunk(alloc::vec![element]);
+            return;
+        }
+
+        // Appending goes into the last chunk rather than starting a new one.
+        let (chunk, offset) = match self.locate(index) {
+            Some(found) => found,
+            None => (
+                self.chunks.len() - 1,
+                self.chunks[self.chunks.len() - 1].len(),
+            ),
+        };
+
+        self.chunks[chunk].insert(offset, element);
+        for end in &mut self.ends[chunk..] {
+            *end += 1;
+        }
+
+        if self.chunks[chunk].len() > MAX_CHUNK {
+            let rest = self.chunks[chunk].inner.split_off(MAX_CHUNK / 2);
+            self.ends.insert(chunk, self.ends[chunk] - rest.len());
+            // Invariant: The split off half has `MAX_CHUNK / 2 + 1` elements.
+            self.chunks.insert(chunk + 1, VecOne::from_inner(rest));
+        }
+    }
+}
+
+// -- Len Decreasing --
+impl<T, const M: usize> RopeMin<T, M> {
+    /// See [`Vec::remove`]. Returns an error if the operation would reduce the length below `M`.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds and the length is greater than `M`.
+    #[track_caller]
+    #[must_use = "this operation may fail"]
+    pub fn remove(&mut self, index: usize) -> Result<T, ModifyError<M>> {
+        let len = self.len();
+        if len <= M {
+            return ModifyError::fail();
+        }
+        let Some((chunk, offset)) = self.locate(index) else {
+            panic!("removal index (is {index}) should be < len (is {len})");
+        };
+
+        for end in &mut self.ends[chunk..] {
+            *end -= 1;
+        }
+        match self.chunks[chunk].remove(offset) {
+            Ok(element) => {
+                self.merge_undersized(chunk);
+                Ok(element)
+            }
+            Err(_) => {
+                self.ends.remove(chunk);
+                match self.chunks.remove(chunk).into_inner().pop() {
+                    Some(element) => Ok(element),
+                    None => unreachable!(),
+                }
+            }
+        }
+    }
+
+    /// See [`Vec::pop`]. Pops an element if the length is greater than `M`, otherwise does nothing and returns `None`.
+    #[inline]
+    pub fn pop_to_min(&mut self) -> Option<T> {
+        match self.len().checked_sub(1) {
+            Some(last) => self.remove(last).ok(),
+            None => None,
+        }
+    }
+
+    /// Merges `chunk` into a neighbour if it is shorter than `MIN_CHUNK` and the neighbour has room for it.
+    fn merge_undersized(&mut self, chunk: usize) {
+        let len = self.chunks[chunk].len();
+        if len >= MIN_CHUNK {
+            return;
+        }
+
+        if chunk + 1 < self.chunks.len() && len + self.chunks[chunk + 1].len() <= MAX_CHUNK {
+            self.merge_with_next(chunk);
+        } else if chunk > 0 && self.chunks[chunk - 1].len() + len <= MAX_CHUNK {
+            self.merge_with_next(chunk - 1);
+        }
+    }
+
+    fn merge_with_next(&mut self, chunk: usize) {
+        let next = self.chunks.remove(chunk + 1).into_inner();
+        self.ends.remove(chunk);
+        self.chunks[chunk].inner.extend(next);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edits() {
+        let mut rope = RopeMin::<_, 2>::new(VecMin::try_from_vec((0..300).collect()).unwrap());
+        assert_eq!(rope.chunks().len(), 3);
+
+        rope.insert(150, 1000);
+        rope.push(300);
+        assert_eq!(
+            (rope.len(), rope.get(150), rope.get(301)),
+            (302, Some(&1000), Some(&300))
+        );
+
+        assert_eq!(rope.remove(150), Ok(1000));
+        while rope.pop_to_min().is_some() {}
+        assert_eq!(rope.remove(0), Err(ModifyError));
+        assert_eq!(*rope.into_vec_min(), [0, 1]);
+    }
+
+    #[test]
+    fn merge_undersized() {
+        let mut rope = RopeMin::<_, 2>::new(VecMin::try_from_vec((0..129).collect()).unwrap());
+        assert_eq!(rope.chunks().len(), 2);
+
+        for _ in 0..96 {
+            rope.remove(0).unwrap();
+        }
+        assert_eq!(rope.chunks().len(), 2);
+        assert_eq!(rope.remove(0), Ok(96));
+        assert_eq!(rope.chunks().len(), 1);
+        assert_eq!(
+            (rope.len(), rope.get(0), rope.get(31)),
+            (32, Some(&97), Some(&128))
+        );
+    }
+}
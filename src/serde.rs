@@ -0,0 +1,360 @@
+//! Helpers for use with `serde`'s field attributes.
+
+use alloc::vec::Vec;
+use core::fmt::{self, Formatter};
+use core::marker::PhantomData;
+
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::VecMin;
+
+/// Returns the [`Default`] `VecMin` of `M` default elements, for use with `#[serde(default = "vecmin::serde::default")]`.
+///
+/// A missing field is filled in with the default, while a present field is still validated by the `Deserialize`
+/// implementation of `VecMin`.
+///
+/// ```
+/// use serde::Deserialize;
+/// use vecmin::VecMin;
+///
+/// #[derive(Deserialize)]
+/// struct Options {
+///     #[serde(default = "vecmin::serde::default")]
+///     weights: VecMin<u8, 1>,
+/// }
+///
+/// let options: Options = serde_json::from_str("{}").unwrap();
+/// assert_eq!(*options.weights, [0]);
+/// assert!(serde_json::from_str::<Options>(r#"{"weights":[]}"#).is_err());
+/// ```
+#[inline]
+pub fn default<T: Default, const M: usize>() -> VecMin<T, M> {
+    VecMin::default()
+}
+
+/// Serde helpers deserializing a [`VecMin<T, M>`] with at most `MAX` elements, for use with
+/// `#[serde(with = "vecmin::serde::Bounded::<MAX>")]`.
+///
+/// Deserialization stops with an error at the first element past `MAX`, so untrusted input cannot make it allocate an
+/// arbitrarily large vector. Serialization is unchanged.
+///
+/// ```
+/// use serde::Deserialize;
+/// use vecmin::VecMin;
+///
+/// #[derive(Deserialize)]
+/// struct Request {
+///     #[serde(with = "vecmin::serde::Bounded::<3>")]
+///     ids: VecMin<u32, 1>,
+/// }
+///
+/// assert!(serde_json::from_str::<Request>(r#"{"ids":[1,2,3]}"#).is_ok());
+/// assert!(serde_json::from_str::<Request>(r#"{"ids":[1,2,3,4]}"#).is_err());
+/// assert!(serde_json::from_str::<Request>(r#"{"ids":[]}"#).is_err());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Bounded<const MAX: usize>;
+
+impl<const MAX: usize> Bounded<MAX> {
+    /// Serializes the vector as a sequence, like the `Serialize` implementation of `VecMin`.
+    #[inline]
+    pub fn serialize<S, T, const M: usize>(
+        vec: &VecMin<T, M>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        vec.serialize(serializer)
+    }
+
+    /// Deserializes the vector from a sequence, returning an error if it has less than `M` or more than `MAX` elements.
+    ///
+    /// Fails to compile if `MAX` is less than `M`.
+    #[inline]
+    pub fn deserialize<'de, D, T, const M: usize>(deserializer: D) -> Result<VecMin<T, M>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        const {
+            assert!(
+                MAX >= M,
+                "the maximum length must be at least the minimum length"
+            )
+        };
+
+        deserializer.deserialize_seq(BoundedVisitor::<T, M, MAX>(PhantomData))
+    }
+}
+
+struct BoundedVisitor<T, const M: usize, const MAX: usize>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>, const M: usize, const MAX: usize> Visitor<'de>
+    for BoundedVisitor<T, M, MAX>
+{
+    type Value = VecMin<T, M>;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "a sequence of at least {M} and at most {MAX} elements")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut vec = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(MAX).min(4096));
+        while let Some(item) = seq.next_element()? {
+            if vec.len() == MAX {
+                return Err(A::Error::invalid_length(MAX + 1, &self));
+            }
+            vec.push(item);
+        }
+
+        let len = vec.len();
+        VecMin::try_from_vec(vec).map_err(|_| A::Error::invalid_length(len, &self))
+    }
+}
+
+pub mod bytes {
+    //! Serializes a [`VecMin<u8, M>`] as a byte string instead of a sequence of integers.
+    //!
+    //! Use with `#[serde(with = "vecmin::serde::bytes")]`. Formats that hand out owned byte buffers are
+    //! deserialized without copying, and the minimum length is validated before the buffer is accepted.
+    //!
+    //! ```
+    //! use serde::{Deserialize, Serialize};
+    //! use vecmin::VecMin;
+    //!
+    //! #[derive(Serialize, Deserialize)]
+    //! struct Packet {
+    //!     #[serde(with = "vecmin::serde::bytes")]
+    //!     header: VecMin<u8, 4>,
+    //! }
+    //! ```
+
+    use alloc::vec::Vec;
+    use core::fmt::{self, Formatter};
+    use core::marker::PhantomData;
+
+    use serde::de::{Error, SeqAccess, Visitor};
+    use serde::{Deserializer, Serializer};
+
+    use crate::VecMin;
+
+    /// Serializes the vector with [`Serializer::serialize_bytes`].
+    #[inline]
+    pub fn serialize<S, const M: usize>(
+        vec: &VecMin<u8, M>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(vec.as_slice())
+    }
+
+    /// Deserializes the vector from a byte string, returning an error if it is shorter than `M`.
+    #[inline]
+    pub fn deserialize<'de, D, const M: usize>(deserializer: D) -> Result<VecMin<u8, M>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_byte_buf(BytesVisitor(PhantomData))
+    }
+
+    struct BytesVisitor<const M: usize>(PhantomData<[(); M]>);
+
+    impl<const M: usize> BytesVisitor<M> {
+        #[inline]
+        fn finish<E: Error>(&self, vec: Vec<u8>) -> Result<VecMin<u8, M>, E> {
+            let len = vec.len();
+            VecMin::try_from_vec(vec).map_err(|_| E::invalid_length(len, self))
+        }
+    }
+
+    impl<'de, const M: usize> Visitor<'de> for BytesVisitor<M> {
+        type Value = VecMin<u8, M>;
+
+        fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            write!(f, "a byte string of at least {M} bytes")
+        }
+
+        #[inline]
+        fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+            if v.len() < M {
+                return Err(E::invalid_length(v.len(), &self));
+            }
+            self.finish(v.to_vec())
+        }
+
+        #[inline]
+        fn visit_byte_buf<E: Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+            self.finish(v)
+        }
+
+        #[inline]
+        fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+            self.visit_bytes(v.as_bytes())
+        }
+
+        #[inline]
+        fn visit_string<E: Error>(self, v: alloc::string::String) -> Result<Self::Value, E> {
+            self.finish(v.into_bytes())
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut vec = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096).max(M));
+            while let Some(byte) = seq.next_element()? {
+                vec.push(byte);
+            }
+            self.finish(vec)
+        }
+    }
+}
+
+pub mod padded {
+    //! Deserializes a [`VecMin<T, M>`] leniently, padding sequences shorter than `M` with `T::default()`.
+    //!
+    //! Use with `#[serde(with = "vecmin::serde::padded")]` to accept data written before the minimum was raised.
+    //! Serialization is unchanged.
+    //!
+    //! ```
+    //! use serde::Deserialize;
+    //! use vecmin::VecMin;
+    //!
+    //! #[derive(Deserialize)]
+    //! struct Config {
+    //!     #[serde(with = "vecmin::serde::padded")]
+    //!     ports: VecMin<u16, 2>,
+    //! }
+    //!
+    //! let config: Config = serde_json::from_str(r#"{"ports":[80]}"#).unwrap();
+    //! assert_eq!(*config.ports, [80, 0]);
+    //! ```
+
+    use alloc::vec::Vec;
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::VecMin;
+
+    /// Serializes the vector as a sequence, like the `Serialize` implementation of `VecMin`.
+    #[inline]
+    pub fn serialize<S, T, const M: usize>(
+        vec: &VecMin<T, M>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        vec.serialize(serializer)
+    }
+
+    /// Deserializes the vector from a sequence, padding it to `M` elements with `T::default()` if it is shorter.
+    #[inline]
+    pub fn deserialize<'de, D, T, const M: usize>(deserializer: D) -> Result<VecMin<T, M>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de> + Default,
+    {
+        let vec = Vec::deserialize(deserializer)?;
+        Ok(VecMin::new_padded_with(vec, T::default))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use serde::{Deserialize, Serialize};
+
+    use crate::VecMin;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Packet {
+        #[serde(with = "crate::serde::bytes")]
+        header: VecMin<u8, 2>,
+    }
+
+    #[test]
+    fn bytes() {
+        let packet = Packet {
+            header: VecMin::try_from([1, 2, 3]).unwrap(),
+        };
+
+        let json = serde_json::to_string(&packet).unwrap();
+        assert_eq!(serde_json::from_str::<Packet>(&json).unwrap(), packet);
+
+        assert!(serde_json::from_str::<Packet>(r#"{"header":[1]}"#).is_err());
+        assert!(serde_json::from_str::<Packet>(r#"{"header":"ab"}"#).is_ok());
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Request {
+        #[serde(with = "crate::serde::Bounded::<2>")]
+        ids: VecMin<u32, 1>,
+    }
+
+    #[test]
+    fn bounded() {
+        let json = r#"{"ids":[1,2]}"#;
+        let request = serde_json::from_str::<Request>(json).unwrap();
+        assert_eq!(serde_json::to_string(&request).unwrap(), json);
+
+        assert!(serde_json::from_str::<Request>(r#"{"ids":[1,2,3]}"#).is_err());
+    }
+
+    #[test]
+    fn bounded_large_min() {
+        use serde::de::value::{Error, SeqDeserializer};
+
+        let deserializer = SeqDeserializer::<_, Error>::new(core::iter::repeat_n(0u8, 5000));
+        let v: VecMin<u8, 5000> = crate::serde::Bounded::<6000>::deserialize(deserializer).unwrap();
+        assert_eq!(v.len(), 5000);
+    }
+
+    #[test]
+    fn too_short() {
+        let err = serde_json::from_str::<VecMin<u8, 2>>("[1]").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid length 1, expected a sequence of at least 2 elements"
+        );
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Options {
+        #[serde(default = "crate::serde::default")]
+        weights: VecMin<u8, 1>,
+    }
+
+    #[test]
+    fn default() {
+        let options = serde_json::from_str::<Options>("{}").unwrap();
+        assert_eq!(*options.weights, [0]);
+
+        assert!(serde_json::from_str::<Options>(r#"{"weights":[]}"#).is_err());
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Config {
+        #[serde(with = "crate::serde::padded")]
+        ports: VecMin<u16, 2>,
+    }
+
+    #[test]
+    fn padded() {
+        let config = serde_json::from_str::<Config>(r#"{"ports":[]}"#).unwrap();
+        assert_eq!(*config.ports, [0, 0]);
+
+        let config = serde_json::from_str::<Config>(r#"{"ports":[1,2,3]}"#).unwrap();
+        assert_eq!(*config.ports, [1, 2, 3]);
+    }
+}
@@ -0,0 +1,190 @@
+//! Implementation of the [`SliceMin`] borrowed slice.
+
+use core::ops::Deref;
+
+use crate::vec::VecMin;
+
+/// A slice with a minimum length of `M`.
+///
+/// `SliceMin` is unsized like `[T]` and is always used behind a reference, so validated slices can be passed around
+/// without allocating. References can be created in const contexts with [`SliceMin::from_array_ref`], which makes it
+/// possible to expose static lookup tables with the guarantee and no runtime validation.
+///
+/// ```
+/// use vecmin::SliceMin;
+///
+/// static PRIMES: &SliceMin<u32, 2> = SliceMin::from_array_ref(&[2, 3, 5, 7]);
+/// assert_eq!(PRIMES.min_slice(), &[2, 3]);
+/// ```
+///
+/// Not available with the `vecmin_no_unsafe` cfg, as creating a reference to an unsized newtype requires a pointer cast.
+#[repr(transparent)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SliceMin<T, const M: usize>([T]);
+
+// --- Constructors, Convertors, and Destructors ---
+impl<T, const M: usize> SliceMin<T, M> {
+    /// Creates a new `&SliceMin` from a `&[T]`.
+    ///
+    /// # Safety
+    /// - The length of the slice must be at least `M`.
+    #[inline]
+    pub const unsafe fn from_slice_unchecked(slice: &[T]) -> &Self {
+        // Safety: `SliceMin` is a transparent wrapper around `[T]`, so the pointer metadata and layout are the same.
+        unsafe { &*(slice as *const [T] as *const Self) }
+    }
+
+    /// Creates a new `&mut SliceMin` from a `&mut [T]`.
+    ///
+    /// # Safety
+    /// - The length of the slice must be at least `M`.
+    #[inline]
+    pub const unsafe fn from_slice_unchecked_mut(slice: &mut [T]) -> &mut Self {
+        // Safety: `SliceMin` is a transparent wrapper around `[T]`, so the pointer metadata and layout are the same.
+        unsafe { &mut *(slice as *mut [T] as *mut Self) }
+    }
+
+    /// Creates a new `&SliceMin` from a `&[T]`, returning `None` if its length is less than `M`.
+    #[inline]
+    pub const fn from_slice(slice: &[T]) -> Option<&Self> {
+        if slice.len() >= M {
+            // Safety: We just checked that the length was at least `M`.
+            Some(unsafe { Self::from_slice_unchecked(slice) })
+        } else {
+            None
+        }
+    }
+
+    /// Creates a new `&SliceMin` from a reference to an array.
+    ///
+    /// Fails to compile if `N` is less than `M`.
+    #[inline]
+    pub const fn from_array_ref<const N: usize>(array: &[T; N]) -> &Self {
+        const { assert!(N >= M, "array length must be at least the minimum length") };
+
+        // Safety: An array of length `N` has a length of at least `M`.
+        unsafe { Self::from_slice_unchecked(array) }
+    }
+
+    /// Returns the minimum length of the slice.
+    #[inline]
+    pub const fn minimum(&self) -> usize {
+        M
+    }
+
+    /// Returns the underlying slice.
+    #[inline]
+    pub const fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    /// Returns the underlying slice as a mutable slice.
+    #[inline]
+    pub const fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+
+    /// Returns the first `M` elements of the slice, which are guaranteed to exist.
+    #[inline]
+    pub const fn min_slice(&self) -> &[T; M] {
+        match self.0.first_chunk() {
+            Some(min) => min,
+            None => unreachable!(),
+        }
+    }
+
+    /// Returns the first `M` elements of the slice as a mutable array, which are guaranteed to exist.
+    #[inline]
+    pub const fn min_slice_mut(&mut self) -> &mut [T; M] {
+        match self.0.first_chunk_mut() {
+            Some(min) => min,
+            None => unreachable!(),
+        }
+    }
+
+    /// Copies the elements into a `VecMin`.
+    #[inline]
+    pub fn to_vec_min(&self) -> VecMin<T, M>
+    where
+        T: Clone,
+    {
+        VecMin::from_inner(self.0.to_vec())
+    }
+}
+
+impl<T, const M: usize> VecMin<T, M> {
+    /// Returns the elements as a `&SliceMin`, which keeps the minimum length guarantee without borrowing the `Vec`.
+    #[inline]
+    pub const fn as_slice_min(&self) -> &SliceMin<T, M> {
+        // Safety: The vector has a length of at least `M`.
+        unsafe { SliceMin::from_slice_unchecked(self.as_slice()) }
+    }
+
+    /// Returns the elements as a `&mut SliceMin`.
+    #[inline]
+    pub const fn as_mut_slice_min(&mut self) -> &mut SliceMin<T, M> {
+        // Safety: The vector has a length of at least `M`, and a `SliceMin` cannot change the length.
+        unsafe { SliceMin::from_slice_unchecked_mut(self.as_mut_slice()) }
+    }
+}
+
+impl<'a, T, const M: usize> TryFrom<&'a [T]> for &'a SliceMin<T, M> {
+    type Error = &'a [T];
+
+    #[inline]
+    fn try_from(slice: &'a [T]) -> Result<Self, Self::Error> {
+        SliceMin::from_slice(slice).ok_or(slice)
+    }
+}
+
+impl<'a, T, const M: usize> From<&'a SliceMin<T, M>> for &'a [T] {
+    #[inline]
+    fn from(slice: &'a SliceMin<T, M>) -> Self {
+        &slice.0
+    }
+}
+
+// --- View ---
+impl<T, const M: usize> Deref for SliceMin<T, M> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const M: usize> AsRef<[T]> for SliceMin<T, M> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<'a, T, const M: usize> IntoIterator for &'a SliceMin<T, M> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vecmin;
+
+    const TABLE: &SliceMin<u8, 2> = SliceMin::from_array_ref(&[1, 2, 3]);
+
+    #[test]
+    fn slice_min() {
+        assert_eq!((TABLE.min_slice(), TABLE.len()), (&[1, 2], 3));
+        assert!(SliceMin::<u8, 4>::from_slice(TABLE).is_none());
+
+        let mut v = vecmin![2; [1, 2, 3]];
+        v.as_mut_slice_min().min_slice_mut()[0] = 0;
+        assert_eq!(v.as_slice_min().to_vec_min(), v);
+    }
+}
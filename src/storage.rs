@@ -0,0 +1,414 @@
+//! Implementation of [`Min`], the minimum length guard shared by every backing container.
+
+use alloc::boxed::Box;
+use alloc::collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque};
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+use crate::ModifyError;
+
+/// A container with a length, which is all [`Min`] needs to guarantee a minimum length for read access.
+///
+/// Implemented for the `alloc` collections, and the `std` hash collections with the `std` feature. `Min` trusts `len`
+/// to report the number of elements, and iterating a reference to the container to yield that many items.
+pub trait Len {
+    /// Returns the number of elements.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if there are no elements.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A container that [`Min`] can enforce a minimum length on while it is modified.
+///
+/// Implement this for your own `Vec`-like types (e.g. arena or memory mapped vectors) to get the guarded operations of
+/// [`Min`] without wrapping every method yourself. `Min` trusts the methods to change the length as documented.
+pub trait Storage: Len {
+    /// The type of the elements.
+    type Item;
+
+    /// Removes the last element and returns it, or `None` if there are no elements.
+    fn pop(&mut self) -> Option<Self::Item>;
+
+    /// Removes the element at `index`, shifting every element after it to the left.
+    ///
+    /// # Panics
+    /// Should panic if `index` is out of bounds.
+    fn remove(&mut self, index: usize) -> Self::Item;
+
+    /// Removes the element at `index`, replacing it with the last element.
+    ///
+    /// # Panics
+    /// Should panic if `index` is out of bounds.
+    fn swap_remove(&mut self, index: usize) -> Self::Item;
+
+    /// Shortens the container to `len` elements, doing nothing if it is already shorter.
+    fn truncate(&mut self, len: usize);
+}
+
+macro_rules! impl_len {
+    ($($(#[$attr:meta])* [$($generics:tt)*] $ty:ty;)*) => {$(
+        $(#[$attr])*
+        impl<$($generics)*> Len for $ty {
+            #[inline]
+            fn len(&self) -> usize {
+                <$ty>::len(self)
+            }
+        }
+    )*};
+}
+
+impl_len! {
+    [T] Vec<T>;
+    [T] VecDeque<T>;
+    [T] LinkedList<T>;
+    [T] BinaryHeap<T>;
+    [K, V] BTreeMap<K, V>;
+    [T] BTreeSet<T>;
+    [] String;
+    #[cfg(feature = "std")]
+    [K, V, S] HashMap<K, V, S>;
+    #[cfg(feature = "std")]
+    [T, S] HashSet<T, S>;
+}
+
+impl<T> Len for Box<[T]> {
+    #[inline]
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+}
+
+impl<T> Storage for Vec<T> {
+    type Item = T;
+
+    #[inline]
+    fn pop(&mut self) -> Option<T> {
+        Vec::pop(self)
+    }
+
+    #[inline]
+    fn remove(&mut self, index: usize) -> T {
+        Vec::remove(self, index)
+    }
+
+    #[inline]
+    fn swap_remove(&mut self, index: usize) -> T {
+        Vec::swap_remove(self, index)
+    }
+
+    #[inline]
+    fn truncate(&mut self, len: usize) {
+        Vec::truncate(self, len);
+    }
+}
+
+/// A container with a minimum length of `M`.
+///
+/// Any [`Len`] container can be wrapped for read access with a guaranteed length (e.g. [`Min::first_item`]). Containers
+/// that also implement [`Storage`] get methods that reduce the length, which return an error or stop at the minimum if
+/// the operation would reduce the length below `M`.
+///
+/// # `VecMin`
+///
+/// [`VecMin<T, M>`](crate::VecMin) is a type alias for `Min<Vec<T>, M>` that adds the rest of `Vec`'s API. Aliases are
+/// expanded by the compiler, so error messages and rustdoc show the type as `Min<Vec<T>, M>` (e.g.
+/// `Min<alloc::vec::Vec<i32>, 1>` for a `VecOne<i32>`), and the `Vec`-specific methods are listed on this page under
+/// `impl<T, const M: usize> Min<Vec<T>, M>`. [`VecMin`](crate::VecMin) itself documents them in one place.
+#[repr(transparent)]
+#[derive(Clone, Debug, Hash)]
+pub struct Min<S, const M: usize> {
+    pub(crate) inner: S,
+}
+
+// --- Constructors, Convertors, and Destructors ---
+impl<S, const M: usize> Min<S, M> {
+    /// Returns the minimum length of the storage.
+    #[inline]
+    pub const fn minimum(&self) -> usize {
+        M
+    }
+
+    /// Returns a reference to the inner storage.
+    #[inline]
+    pub const fn storage(&self) -> &S {
+        &self.inner
+    }
+
+    /// Returns the inner storage, consuming the `Min`.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    /// Converts into a `Min` with a lower minimum length, without touching the storage.
+    ///
+    /// Fails to compile if `N` is greater than `M`.
+    #[inline]
+    pub fn relax<const N: usize>(self) -> Min<S, N> {
+        const {
+            assert!(
+                N <= M,
+                "relaxed minimum length must not exceed the current one"
+            )
+        };
+
+        // Invariant: The length is at least `M`, which is at least `N`.
+        Min::from_inner(self.inner)
+    }
+}
+
+impl<S, const M: usize> Min<S, M> {
+    /// Creates a new `Min` without checking the length, for use within the crate where the caller upholds the
+    /// invariant. Unlike the public `_unchecked` constructors this is available with the `vecmin_no_unsafe` cfg.
+    #[inline]
+    pub(crate) const fn from_inner(inner: S) -> Self {
+        Self { inner }
+    }
+}
+
+impl<S: Len, const M: usize> Min<S, M> {
+    /// Creates a new `Min` from a storage.
+    ///
+    /// # Safety
+    /// - The length of the storage must be at least `M`.
+    #[cfg(not(vecmin_no_unsafe))]
+    #[inline]
+    pub unsafe fn from_storage_unchecked(storage: S) -> Self {
+        Self::from_inner(storage)
+    }
+
+    /// Creates a new `Min` from a storage.
+    ///
+    /// The `vecmin_no_unsafe` cfg makes this function safe by checking the length.
+    ///
+    /// # Panics
+    /// Panics if the length of the storage is less than `M`.
+    #[cfg(vecmin_no_unsafe)]
+    #[inline]
+    #[track_caller]
+    pub fn from_storage_unchecked(storage: S) -> Self {
+        assert!(storage.len() >= M, "length is less than the minimum");
+        Self::from_inner(storage)
+    }
+
+    /// Creates a new `Min` from a storage, returning it back as an error if its length is less than `M`.
+    #[inline]
+    pub fn try_from_storage(storage: S) -> Result<Self, S> {
+        if storage.len() >= M {
+            // Invariant: We just checked that the length was at least `M`.
+            Ok(Self::from_inner(storage))
+        } else {
+            Err(storage)
+        }
+    }
+
+    /// Converts into a `Min` with a higher minimum length, returning `self` back as an error if the length is less
+    /// than `N`.
+    #[inline]
+    pub fn tighten<const N: usize>(self) -> Result<Min<S, N>, Self> {
+        if self.inner.len() >= N {
+            // Invariant: We just checked that the length was at least `N`.
+            Ok(Min::from_inner(self.inner))
+        } else {
+            Err(self)
+        }
+    }
+}
+
+// --- Immutable Access ---
+impl<S, const M: usize> Min<S, M>
+where
+    for<'a> &'a S: IntoIterator,
+{
+    /// Returns the first item of the container, which is guaranteed to exist.
+    ///
+    /// Fails to compile if `M` is 0.
+    #[inline]
+    pub fn first_item(&self) -> <&S as IntoIterator>::Item {
+        self.split_first_item().0
+    }
+
+    /// Returns the first item of the container, which is guaranteed to exist, and an iterator over the rest.
+    ///
+    /// Fails to compile if `M` is 0.
+    #[inline]
+    pub fn split_first_item(&self) -> (<&S as IntoIterator>::Item, <&S as IntoIterator>::IntoIter) {
+        const {
+            assert!(
+                M >= 1,
+                "`split_first_item` requires a minimum length of at least 1"
+            )
+        };
+
+        let mut iter = (&self.inner).into_iter();
+        match iter.next() {
+            Some(first) => (first, iter),
+            None => unreachable!(),
+        }
+    }
+}
+
+// --- Mutable Access ---
+
+// -- Len Decreasing --
+impl<S: Storage, const M: usize> Min<S, M> {
+    /// See [`Vec::pop`]. Pops an element if the length is greater than `M`, otherwise does nothing and returns `None`.
+    #[inline]
+    pub fn pop_to_min(&mut self) -> Option<S::Item> {
+        if self.inner.len() > M {
+            self.inner.pop()
+        } else {
+            None
+        }
+    }
+
+    /// See [`Vec::remove`]. Returns an error if the operation would reduce the length below `M`.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn remove(&mut self, index: usize) -> Result<S::Item, ModifyError<M>> {
+        if self.inner.len() > M {
+            Ok(self.inner.remove(index))
+        } else {
+            ModifyError::fail()
+        }
+    }
+
+    /// See [`Vec::swap_remove`]. Returns an error if the operation would reduce the length below `M`.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn swap_remove(&mut self, index: usize) -> Result<S::Item, ModifyError<M>> {
+        if self.inner.len() > M {
+            Ok(self.inner.swap_remove(index))
+        } else {
+            ModifyError::fail()
+        }
+    }
+
+    /// See [`Vec::remove`]. Removes the element at `index` if the length is greater than `M`, otherwise does nothing and returns `None`.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds and the length is greater than `M`.
+    #[inline]
+    pub fn remove_or_min(&mut self, index: usize) -> Option<S::Item> {
+        self.remove(index).ok()
+    }
+
+    /// See [`Vec::swap_remove`]. Removes the element at `index` if the length is greater than `M`, otherwise does nothing and returns `None`.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds and the length is greater than `M`.
+    #[inline]
+    pub fn swap_remove_or_min(&mut self, index: usize) -> Option<S::Item> {
+        self.swap_remove(index).ok()
+    }
+
+    /// See [`Vec::truncate`]. Returns an error if the operation would reduce the length below `M`.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn truncate(&mut self, len: usize) -> Result<(), ModifyError<M>> {
+        if len >= M {
+            self.inner.truncate(len);
+            Ok(())
+        } else {
+            ModifyError::fail()
+        }
+    }
+
+    /// See [`Vec::truncate`]. Truncates to `len` if `len` is greater than or equal to `M`, otherwise truncates to `M`.
+    #[inline]
+    pub fn truncate_or_min(&mut self, len: usize) {
+        self.inner.truncate(len.max(M));
+    }
+
+    /// See [`Vec::truncate`]. Truncates to `M`.
+    #[inline]
+    pub fn truncate_to_min(&mut self) {
+        self.inner.truncate(M);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A fixed capacity stack standing in for a user container.
+    #[derive(Debug)]
+    struct Stack {
+        items: [u8; 4],
+        len: usize,
+    }
+
+    impl Len for Stack {
+        fn len(&self) -> usize {
+            self.len
+        }
+    }
+
+    impl Storage for Stack {
+        type Item = u8;
+
+        fn pop(&mut self) -> Option<u8> {
+            self.len = self.len.checked_sub(1)?;
+            Some(self.items[self.len])
+        }
+
+        fn remove(&mut self, index: usize) -> u8 {
+            let item = self.items[..self.len][index];
+            self.items.copy_within(index + 1..self.len, index);
+            self.len -= 1;
+            item
+        }
+
+        fn swap_remove(&mut self, index: usize) -> u8 {
+            let item = self.items[..self.len][index];
+            self.items[index] = self.items[self.len - 1];
+            self.len -= 1;
+            item
+        }
+
+        fn truncate(&mut self, len: usize) {
+            self.len = self.len.min(len);
+        }
+    }
+
+    #[test]
+    fn custom_storage() {
+        let stack = Stack {
+            items: [1, 2, 3, 4],
+            len: 4,
+        };
+        let mut min = Min::<_, 2>::try_from_storage(stack).unwrap();
+
+        assert_eq!(min.remove(0), Ok(1));
+        assert_eq!(min.pop_to_min(), Some(4));
+        assert_eq!(min.pop_to_min(), None);
+        min.truncate(1).unwrap_err();
+        assert_eq!(min.storage().items[..min.storage().len], [2, 3]);
+    }
+
+    #[test]
+    fn read_only() {
+        let deque = VecDeque::from([3, 1, 2]);
+        let min = Min::<_, 2>::try_from_storage(deque).unwrap();
+
+        let (first, rest) = min.split_first_item();
+        assert_eq!((first, rest.len()), (&3, 2));
+        Min::<_, 4>::try_from_storage(String::from("abc")).unwrap_err();
+    }
+
+    #[test]
+    fn relax_tighten() {
+        let min = Min::<_, 3>::try_from_storage(String::from("abc")).unwrap();
+        let min = min.relax::<1>();
+        assert_eq!(min.minimum(), 1);
+
+        let min = min.tighten::<4>().unwrap_err().tighten::<3>().unwrap();
+        assert_eq!((min.minimum(), min.storage().as_str()), (3, "abc"));
+    }
+}
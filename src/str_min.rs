@@ -0,0 +1,175 @@
+//! Implementation of the [`StrMin`] borrowed string slice.
+
+use core::fmt::{self, Debug, Display, Formatter};
+use core::ops::Deref;
+
+use crate::vec::VecMin;
+
+/// A string slice with a minimum length of `M` bytes.
+///
+/// `StrMin` is unsized like `str` and is always used behind a reference, so validated strings can be passed around
+/// without allocating. The first `M` bytes are available as an array with [`StrMin::as_bytes_min`], and read access to
+/// the string is available through `Deref<Target = str>`.
+///
+/// Not available with the `vecmin_no_unsafe` cfg, as creating a reference to an unsized newtype requires a pointer cast.
+#[repr(transparent)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StrMin<const M: usize>(str);
+
+// --- Constructors, Convertors, and Destructors ---
+impl<const M: usize> StrMin<M> {
+    /// Creates a new `&StrMin` from a `&str`.
+    ///
+    /// # Safety
+    /// - The length of the `str` in bytes must be at least `M`.
+    #[inline]
+    pub const unsafe fn from_str_unchecked(s: &str) -> &Self {
+        // Safety: `StrMin` is a transparent wrapper around `str`, so the pointer metadata and layout are the same.
+        unsafe { &*(s as *const str as *const Self) }
+    }
+
+    /// Creates a new `&StrMin` from a `&str`, returning `None` if its length in bytes is less than `M`.
+    #[inline]
+    pub const fn from_str(s: &str) -> Option<&Self> {
+        if s.len() >= M {
+            // Safety: We just checked that the length was at least `M`.
+            Some(unsafe { Self::from_str_unchecked(s) })
+        } else {
+            None
+        }
+    }
+
+    /// Creates a new `&StrMin` from a `&str`, returning `None` if it has less than `M` characters.
+    ///
+    /// Every character is at least one byte, so a string of `M` characters also satisfies the minimum length in bytes.
+    #[inline]
+    pub fn from_str_chars(s: &str) -> Option<&Self> {
+        if M == 0 || s.chars().nth(M - 1).is_some() {
+            // Safety: There are at least `M` characters, each of which is at least one byte.
+            Some(unsafe { Self::from_str_unchecked(s) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the minimum length of the string in bytes.
+    #[inline]
+    pub const fn minimum(&self) -> usize {
+        M
+    }
+
+    /// Returns the underlying `str`.
+    #[inline]
+    pub const fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the first `M` bytes of the string, which are guaranteed to exist.
+    ///
+    /// The bytes may end in the middle of a multi-byte character.
+    #[inline]
+    pub const fn as_bytes_min(&self) -> &[u8; M] {
+        match self.0.as_bytes().first_chunk() {
+            Some(min) => min,
+            None => unreachable!(),
+        }
+    }
+
+    /// Copies the bytes of the string into a `VecMin`.
+    #[inline]
+    pub fn to_bytes(&self) -> VecMin<u8, M> {
+        VecMin::from_inner(self.0.as_bytes().to_vec())
+    }
+}
+
+impl<'a, const M: usize> TryFrom<&'a str> for &'a StrMin<M> {
+    type Error = &'a str;
+
+    #[inline]
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        StrMin::from_str(s).ok_or(s)
+    }
+}
+
+impl<'a, const M: usize> From<&'a StrMin<M>> for &'a str {
+    #[inline]
+    fn from(s: &'a StrMin<M>) -> Self {
+        &s.0
+    }
+}
+
+// --- View ---
+impl<const M: usize> Deref for StrMin<M> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const M: usize> AsRef<str> for StrMin<M> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<const M: usize> AsRef<[u8]> for StrMin<M> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl<const M: usize> Debug for StrMin<M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl<const M: usize> Display for StrMin<M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+// --- Equality ---
+impl<const M: usize> PartialEq<str> for StrMin<M> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<const M: usize> PartialEq<&str> for StrMin<M> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == **other
+    }
+}
+
+impl<const M: usize> PartialEq<StrMin<M>> for str {
+    #[inline]
+    fn eq(&self, other: &StrMin<M>) -> bool {
+        *self == other.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes_and_chars() {
+        let s = StrMin::<3>::from_str("héllo").unwrap();
+        assert_eq!(s.as_bytes_min(), b"h\xc3\xa9");
+        assert_eq!(*s, *"héllo");
+
+        assert!(StrMin::<3>::from_str("hi").is_none());
+        assert!(StrMin::<3>::from_str("é").is_none());
+        assert!(StrMin::<2>::from_str("é").is_some());
+        assert!(StrMin::<2>::from_str_chars("é").is_none());
+        assert!(StrMin::<0>::from_str_chars("").is_some());
+    }
+}
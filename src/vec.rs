@@ -2,17 +2,20 @@
 
 use alloc::borrow::Cow;
 use alloc::boxed::Box;
-use alloc::collections::TryReserveError;
+use alloc::collections::{BTreeMap, BTreeSet, TryReserveError, btree_map};
 use alloc::vec::{self, Vec};
 use core::borrow::{Borrow, BorrowMut};
+use core::cell::Cell;
 use core::cmp::Ordering;
 use core::error::Error;
 use core::fmt::{self, Debug, Display, Formatter};
-use core::iter::repeat_with;
+use core::iter::{Cycle, Sum, repeat_with};
 use core::mem::MaybeUninit;
-use core::ops::{Deref, DerefMut, RangeBounds};
+use core::ops::{Deref, DerefMut, Range, RangeBounds};
 use core::slice;
 
+use crate::iter::{ExtractIfMin, Frames, FramesMut, IterTagged, IterTaggedMut};
+use crate::storage::Min;
 use crate::{ModifyError, slice_range};
 
 /// A [`VecMin`] with a minimum length of 1.
@@ -23,11 +26,16 @@ pub type VecOne<T> = VecMin<T, 1>;
 /// Most methods of `Vec` are available on `VecMin` except those that reduce the length of the vector an unknown amount.
 /// Methods that reduce the length of the vector by a known amount (e.g. `remove`, `truncate`) are available on `VecMin`
 /// but return an error if the operation would reduce the length of the vector below `M`.
-#[repr(transparent)]
-#[derive(Clone, Debug, Hash)]
-pub struct VecMin<T, const M: usize> {
-    vec: Vec<T>,
-}
+///
+/// `VecMin` is the `Vec` instantiation of [`Min`], which provides the guarded operations shared with other storages.
+///
+/// # Panic Safety
+/// No method leaves the vector with fewer than `M` elements when it panics, including panics from user code such as an
+/// iterator passed to `extend`, a `Clone` implementation, or a destructor. Growing methods only ever increase the
+/// length, and methods that reduce it never go below the final length they were checked against. Elements are never
+/// leaked or dropped twice, so a vector recovered with [`catch_unwind`](https://doc.rust-lang.org/std/panic/fn.catch_unwind.html)
+/// can keep being used.
+pub type VecMin<T, const M: usize> = Min<Vec<T>, M>;
 
 // --- Custom ---
 impl<T, const M: usize> VecMin<T, M> {
@@ -35,26 +43,32 @@ impl<T, const M: usize> VecMin<T, M> {
     #[inline]
     #[track_caller]
     pub const fn assert_invariant(&self) {
-        assert!(self.vec.len() >= M);
+        assert!(self.inner.len() >= M);
     }
 
     /// Debug assertion that that the length of the vector is at least `M`.
     #[inline]
     #[track_caller]
     pub const fn debug_assert_invariant(&self) {
-        debug_assert!(self.vec.len() >= M);
+        debug_assert!(self.inner.len() >= M);
     }
 
-    /// Returns the minimum length of the vector.
+    /// Returns `true` if the length of the vector is equal to the minimum length `M`.
     #[inline]
-    pub const fn minimum(&self) -> usize {
-        M
+    pub const fn is_minimum(&self) -> bool {
+        self.inner.len() == M
     }
 
-    /// Returns `true` if the length of the vector is equal to the minimum length `M`.
+    /// Returns the number of elements that can be removed before the length reaches `M`.
     #[inline]
-    pub const fn is_minimum(&self) -> bool {
-        self.vec.len() == M
+    pub const fn surplus_len(&self) -> usize {
+        self.inner.len() - M
+    }
+
+    /// Returns `true` if `n` elements can be removed without reducing the length below `M`.
+    #[inline]
+    pub const fn can_remove(&self, n: usize) -> bool {
+        n <= self.surplus_len()
     }
 
     /// Returns a slice to the first `M` elements of the vector, which are guaranteed to exist.
@@ -69,14 +83,37 @@ impl<T, const M: usize> VecMin<T, M> {
         self.split_at_min_mut().0
     }
 
+    /// Returns a slice to the last `M` elements of the vector, which are guaranteed to exist.
+    #[inline]
+    pub const fn last_min_slice(&self) -> &[T; M] {
+        self.debug_assert_invariant();
+
+        match self.inner.as_slice().last_chunk() {
+            Some(last) => last,
+            None => unreachable!(),
+        }
+    }
+
+    /// Returns a mutable slice to the last `M` elements of the vector, which are guaranteed to exist.
+    #[inline]
+    pub const fn last_min_slice_mut(&mut self) -> &mut [T; M] {
+        self.debug_assert_invariant();
+
+        match self.inner.as_mut_slice().last_chunk_mut() {
+            Some(last) => last,
+            None => unreachable!(),
+        }
+    }
+
     /// Returns a tuple of a slice to the first `M` elements of the vector, which are guaranteed to exist, and a slice to the remaining elements of the vector.
     #[inline]
     pub const fn split_at_min(&self) -> (&[T; M], &[T]) {
         self.debug_assert_invariant();
 
-        let (min, extra) = unsafe { self.vec.as_slice().split_at_unchecked(M) };
-        let min = unsafe { &*(min.as_ptr() as *const [T; M]) };
-        (min, extra)
+        match self.inner.as_slice().split_first_chunk() {
+            Some(split) => split,
+            None => unreachable!(),
+        }
     }
 
     /// Returns a tuple of a mutable slice to the first `M` elements of the vector, which are guaranteed to exist, and a mutable slice to the remaining elements of the vector.
@@ -84,9 +121,36 @@ impl<T, const M: usize> VecMin<T, M> {
     pub const fn split_at_min_mut(&mut self) -> (&mut [T; M], &mut [T]) {
         self.debug_assert_invariant();
 
-        let (min, extra) = unsafe { self.vec.as_mut_slice().split_at_mut_unchecked(M) };
-        let min = unsafe { &mut *(min.as_mut_ptr() as *mut [T; M]) };
-        (min, extra)
+        match self.inner.as_mut_slice().split_first_chunk_mut() {
+            Some(split) => split,
+            None => unreachable!(),
+        }
+    }
+
+    /// Returns a tuple of a slice to the first `K` elements of the vector, which are guaranteed to exist, and a slice to the remaining elements of the vector.
+    ///
+    /// Fails to compile if `K` is greater than `M`.
+    #[inline]
+    pub const fn split_prefix<const K: usize>(&self) -> (&[T; K], &[T]) {
+        const { assert!(K <= M, "prefix length must be at most the minimum length") };
+
+        match self.inner.as_slice().split_first_chunk() {
+            Some(split) => split,
+            None => unreachable!(),
+        }
+    }
+
+    /// Returns a tuple of a mutable slice to the first `K` elements of the vector, which are guaranteed to exist, and a mutable slice to the remaining elements of the vector.
+    ///
+    /// Fails to compile if `K` is greater than `M`.
+    #[inline]
+    pub const fn split_prefix_mut<const K: usize>(&mut self) -> (&mut [T; K], &mut [T]) {
+        const { assert!(K <= M, "prefix length must be at most the minimum length") };
+
+        match self.inner.as_mut_slice().split_first_chunk_mut() {
+            Some(split) => split,
+            None => unreachable!(),
+        }
     }
 }
 
@@ -109,22 +173,107 @@ impl<T: Debug, const M: usize> Display for ConstructError<T, M> {
 
 impl<T: Debug, const M: usize> Error for ConstructError<T, M> {}
 
+/// An error returned by [`VecMin::drain_min`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DrainMinError<const M: usize, const K: usize> {
+    /// Draining would reduce the length of the vector below `M`.
+    Modify(ModifyError<M>),
+    /// The range has less than `K` elements, the contained value is its length.
+    TooShort(usize),
+}
+
+impl<const M: usize, const K: usize> From<ModifyError<M>> for DrainMinError<M, K> {
+    #[inline]
+    fn from(err: ModifyError<M>) -> Self {
+        Self::Modify(err)
+    }
+}
+
+impl<const M: usize, const K: usize> Display for DrainMinError<M, K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Modify(err) => Display::fmt(err, f),
+            Self::TooShort(len) => write!(
+                f,
+                "Length {len} of the drained range is less than the minimum {K}"
+            ),
+        }
+    }
+}
+
+impl<const M: usize, const K: usize> Error for DrainMinError<M, K> {}
+
+/// An error returned by [`VecMin::collect_exact`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CollectExactError<T, const M: usize> {
+    /// The expected count is less than `M`, so no items were collected.
+    BelowMinimum {
+        /// The expected number of items.
+        expected: usize,
+    },
+    /// The iterator yielded fewer items than expected.
+    TooFew {
+        /// The expected number of items.
+        expected: usize,
+        /// Every item the iterator yielded.
+        items: Vec<T>,
+    },
+    /// The iterator yielded more items than expected.
+    TooMany {
+        /// The expected number of items.
+        expected: usize,
+        /// The items up to and including the first unexpected one, the rest were left in the iterator.
+        items: Vec<T>,
+    },
+}
+
+impl<T, const M: usize> Display for CollectExactError<T, M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BelowMinimum { expected } => {
+                write!(f, "Expected count {expected} is less than the minimum {M}")
+            }
+            Self::TooFew { expected, items } => {
+                write!(f, "Expected {expected} items but got {}", items.len())
+            }
+            Self::TooMany { expected, .. } => write!(f, "Expected {expected} items but got more"),
+        }
+    }
+}
+
+impl<T: Debug, const M: usize> Error for CollectExactError<T, M> {}
+
 impl<T, const M: usize> VecMin<T, M> {
     /// Creates a new `VecMin` from a `Vec`
     ///
     /// # Safety
     /// - The length of the `Vec` must be at least `M`.
+    #[cfg(not(vecmin_no_unsafe))]
     #[inline]
     pub const unsafe fn from_vec_unchecked(vec: Vec<T>) -> Self {
-        Self { vec }
+        Self::from_inner(vec)
+    }
+
+    /// Creates a new `VecMin` from a `Vec`
+    ///
+    /// The `vecmin_no_unsafe` cfg makes this function safe by checking the length.
+    ///
+    /// # Panics
+    /// Panics if the length of the `Vec` is less than `M`.
+    #[cfg(vecmin_no_unsafe)]
+    #[inline]
+    #[track_caller]
+    pub const fn from_vec_unchecked(vec: Vec<T>) -> Self {
+        assert!(vec.len() >= M, "length is less than the minimum");
+        Self::from_inner(vec)
     }
 
     /// Creates a new `VecMin` from a `Vec`, returning an error if the length of the provided `Vec` is less than `M`.
     #[inline]
     pub const fn try_from_vec(vec: Vec<T>) -> Result<Self, ConstructError<T, M>> {
         if vec.len() >= M {
-            // Safety: We just checked that the length was at least `M`.
-            Ok(unsafe { Self::from_vec_unchecked(vec) })
+            // Invariant: We just checked that the length was at least `M`.
+            Ok(Self::from_inner(vec))
         } else {
             Err(ConstructError(vec))
         }
@@ -139,8 +288,44 @@ impl<T, const M: usize> VecMin<T, M> {
     /// Creates a new `VecMin` from an array containing the minimum elements.
     #[inline]
     pub fn from_array(array: [T; M]) -> Self {
-        // Safety: An array of length `M` is guaranteed to have a length of at least `M`.
-        unsafe { Self::from_vec_unchecked(array.into()) }
+        // Invariant: An array of length `M` is guaranteed to have a length of at least `M`.
+        Self::from_inner(array.into())
+    }
+
+    /// Creates a new `VecMin` from an array with at least the minimum elements.
+    ///
+    /// Fails to compile if `N` is less than `M`.
+    #[inline]
+    pub fn from_larger_array<const N: usize>(array: [T; N]) -> Self {
+        const { assert!(N >= M, "array length must be at least the minimum length") };
+
+        // Invariant: An array of length `N` has a length of at least `M`.
+        Self::from_inner(array.into())
+    }
+
+    /// Creates a new `VecMin` of `N` clones of `elem`, used by the repeat form of [`vecmin!`](crate::vecmin).
+    ///
+    /// Fails to compile if `N` is less than `M`.
+    #[doc(hidden)]
+    #[inline]
+    pub fn __from_elem<const N: usize>(elem: T) -> Self
+    where
+        T: Clone,
+    {
+        const { assert!(N >= M, "element count must be at least the minimum length") };
+
+        // Invariant: The `Vec` has `N` elements, which is at least `M`.
+        Self::from_inner(alloc::vec![elem; N])
+    }
+
+    /// Creates a new `VecMin` from an array of the first `M` elements and a `Vec` of the remaining elements.
+    ///
+    /// The inverse of [`VecMin::into_min_parts`]. The allocation of `tail` is reused, and only grown if it cannot hold
+    /// the `M` extra elements.
+    pub fn from_head_tail(head: [T; M], mut tail: Vec<T>) -> Self {
+        tail.splice(0..0, head);
+        // Invariant: `head` added `M` elements.
+        Self::from_inner(tail)
     }
 
     /// Creates a new `VecMin` from an iterator, returning an error if the length of the collected `Vec` is less than `M`.
@@ -152,6 +337,40 @@ impl<T, const M: usize> VecMin<T, M> {
         Self::collect_with_capacity(iter, high.unwrap_or(low).max(M))
     }
 
+    /// Creates a new `VecMin` from an iterator that must yield exactly `n` items, returning an error if `n` is less than
+    /// `M` or the iterator yields a different number of items.
+    ///
+    /// The iterator is not advanced past the first unexpected item.
+    pub fn collect_exact(
+        iter: impl IntoIterator<Item = T>,
+        n: usize,
+    ) -> Result<Self, CollectExactError<T, M>> {
+        if n < M {
+            return Err(CollectExactError::BelowMinimum { expected: n });
+        }
+
+        // `n` often comes from untrusted input, so only trust it as far as the iterator agrees.
+        let mut iter = iter.into_iter();
+        let mut vec = Vec::with_capacity(iter.size_hint().0.min(n));
+        vec.extend(iter.by_ref().take(n));
+
+        if vec.len() < n {
+            Err(CollectExactError::TooFew {
+                expected: n,
+                items: vec,
+            })
+        } else if let Some(extra) = iter.next() {
+            vec.push(extra);
+            Err(CollectExactError::TooMany {
+                expected: n,
+                items: vec,
+            })
+        } else {
+            // Invariant: We collected `n` items, which is at least `M`.
+            Ok(Self::from_inner(vec))
+        }
+    }
+
     /// Creates a new `VecMin` from an iterator, returning an error if the length of the collected `Vec` is less than `M`.
     /// The provided `capacity` is preallocated into the `Vec`.
     #[inline]
@@ -165,36 +384,165 @@ impl<T, const M: usize> VecMin<T, M> {
         Self::try_new(vec)
     }
 
-    /// Returns the inner `Vec`, consuming the `VecMin`.
+    /// Creates a new `VecMin` from a `Vec`, extending it with clones of `fill` if its length is less than `M`.
     #[inline]
-    pub fn into_inner(self) -> Vec<T> {
-        self.vec
+    pub fn new_padded(mut vec: Vec<T>, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        if vec.len() < M {
+            vec.resize(M, fill);
+        }
+        // Invariant: We just padded the length to at least `M`.
+        Self::from_inner(vec)
+    }
+
+    /// Creates a new `VecMin` from a `Vec`, extending it with the results of calling `f` if its length is less than `M`.
+    #[inline]
+    pub fn new_padded_with(mut vec: Vec<T>, f: impl FnMut() -> T) -> Self {
+        if vec.len() < M {
+            vec.resize_with(M, f);
+        }
+        // Invariant: We just padded the length to at least `M`.
+        Self::from_inner(vec)
+    }
+
+    /// Moves the first `M` elements into an array and returns it with the remaining elements, consuming the vector.
+    ///
+    /// No element is cloned, and the remaining elements keep the allocation of the vector.
+    pub fn into_min_parts(self) -> ([T; M], Vec<T>) {
+        let mut tail = self.inner;
+        let head = {
+            let mut head = tail.drain(..M);
+            core::array::from_fn(|_| match head.next() {
+                Some(item) => item,
+                None => unreachable!(),
+            })
+        };
+        (head, tail)
     }
 
     /// Returns a reference to the inner `Vec`.
     #[inline]
     pub fn vec(&self) -> &Vec<T> {
-        &self.vec
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner `Vec`.
+    ///
+    /// Not available with the `vecmin_no_unsafe` cfg.
+    ///
+    /// # Safety
+    /// - The length of the `Vec` must be at least `M` again before the `VecMin` is used, except for calling
+    ///   [`VecMin::ensure_min_with`] which restores it.
+    #[cfg(not(vecmin_no_unsafe))]
+    #[inline]
+    pub unsafe fn as_mut_vec(&mut self) -> &mut Vec<T> {
+        &mut self.inner
     }
 
     /// See [`Vec::into_boxed_slice`].
     #[inline]
     pub fn into_boxed_slice(self) -> Box<[T]> {
-        self.vec.into_boxed_slice()
+        self.inner.into_boxed_slice()
     }
 
     /// See [`Vec::leak`].
     #[inline]
     pub fn leak(self) -> &'static mut [T] {
-        self.vec.leak()
+        self.inner.leak()
+    }
+}
+
+impl<T, const M: usize> VecMin<T, M> {
+    /// Reverses the order of the elements in place, consuming and returning the vector.
+    #[inline]
+    pub fn into_reversed(mut self) -> Self {
+        self.inner.reverse();
+        self
+    }
+
+    /// Returns a copy of the vector with the elements in reverse order.
+    #[inline]
+    pub fn to_reversed(&self) -> Self
+    where
+        T: Clone,
+    {
+        // Invariant: The copy has the same length as the vector.
+        Self::from_inner(self.inner.iter().rev().cloned().collect())
+    }
+
+    /// Sorts the elements, consuming and returning the vector, see [`slice::sort`].
+    #[inline]
+    pub fn into_sorted(mut self) -> Self
+    where
+        T: Ord,
+    {
+        self.inner.sort();
+        self
+    }
+
+    /// Sorts the elements with the comparator `compare`, consuming and returning the vector, see [`slice::sort_by`].
+    #[inline]
+    pub fn into_sorted_by<F>(mut self, compare: F) -> Self
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.inner.sort_by(compare);
+        self
+    }
+
+    /// Sorts the elements by the key returned by `key`, consuming and returning the vector, see
+    /// [`slice::sort_by_key`].
+    #[inline]
+    pub fn into_sorted_by_key<K, F>(mut self, key: F) -> Self
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.inner.sort_by_key(key);
+        self
+    }
+
+    /// Returns a sorted copy of the vector, see [`slice::sort`].
+    #[inline]
+    pub fn to_sorted(&self) -> Self
+    where
+        T: Clone + Ord,
+    {
+        self.clone().into_sorted()
     }
 }
 
 impl<T: Default, const M: usize> Default for VecMin<T, M> {
     #[inline]
     fn default() -> Self {
-        // Safety: We collect `M` elements satisfying the minimum length requirement.
-        unsafe { Self::from_vec_unchecked(repeat_with(T::default).take(M).collect()) }
+        // Invariant: We collect `M` elements satisfying the minimum length requirement.
+        Self::from_inner(repeat_with(T::default).take(M).collect())
+    }
+}
+
+impl<T> VecOne<T> {
+    /// Creates a new `VecOne` containing only `item`.
+    #[inline]
+    pub fn singleton(item: T) -> Self {
+        Self::from_array([item])
+    }
+}
+
+impl<T> VecMin<T, 2> {
+    /// Creates a new `VecMin` containing `a` and `b`.
+    #[inline]
+    pub fn pair(a: T, b: T) -> Self {
+        Self::from_array([a, b])
+    }
+}
+
+impl<T> VecMin<T, 3> {
+    /// Creates a new `VecMin` containing `a`, `b` and `c`.
+    #[inline]
+    pub fn triple(a: T, b: T, c: T) -> Self {
+        Self::from_array([a, b, c])
     }
 }
 
@@ -210,7 +558,7 @@ impl<T, const M: usize> TryFrom<Vec<T>> for VecMin<T, M> {
 impl<T, const M: usize> From<VecMin<T, M>> for Vec<T> {
     #[inline]
     fn from(vec_min: VecMin<T, M>) -> Self {
-        vec_min.vec
+        vec_min.inner
     }
 }
 
@@ -226,7 +574,7 @@ impl<T, const M: usize> TryFrom<Box<[T]>> for VecMin<T, M> {
 impl<T, const M: usize> From<VecMin<T, M>> for Box<[T]> {
     #[inline]
     fn from(vec_min: VecMin<T, M>) -> Self {
-        vec_min.vec.into_boxed_slice()
+        vec_min.inner.into_boxed_slice()
     }
 }
 
@@ -257,6 +605,26 @@ impl<T: Clone, const M: usize> TryFrom<Cow<'_, [T]>> for VecMin<T, M> {
     }
 }
 
+impl<const M: usize> TryFrom<&str> for VecMin<char, M> {
+    type Error = ConstructError<char, M>;
+
+    /// Collects the characters of the string, returning an error if there are less than `M`.
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::try_from_vec(s.chars().collect())
+    }
+}
+
+impl<const M: usize> TryFrom<&str> for VecMin<u8, M> {
+    type Error = ConstructError<u8, M>;
+
+    /// Copies the bytes of the string, returning an error if there are less than `M`.
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::try_new(s.as_bytes())
+    }
+}
+
 impl<T, const N: usize, const M: usize> TryFrom<[T; N]> for VecMin<T, M> {
     type Error = ConstructError<T, M>;
 
@@ -289,11 +657,8 @@ impl<T, const N: usize, const M: usize> TryFrom<VecMin<T, M>> for [T; N] {
 
     #[inline]
     fn try_from(vec_min: VecMin<T, M>) -> Result<[T; N], Self::Error> {
-        // Safety: We obtained the original `Vec` from a valid `VecMin`.
-        vec_min
-            .vec
-            .try_into()
-            .map_err(|vec| unsafe { VecMin::from_vec_unchecked(vec) })
+        // Invariant: We obtained the original `Vec` from a valid `VecMin`.
+        vec_min.inner.try_into().map_err(VecMin::from_inner)
     }
 }
 
@@ -302,31 +667,110 @@ impl<T, const M: usize> VecMin<T, M> {
     #[inline]
     /// See [`Vec::as_slice`].
     pub const fn as_slice(&self) -> &[T] {
-        self.vec.as_slice()
+        self.inner.as_slice()
     }
 
     /// See [`Vec::as_mut_slice`].
     #[inline]
     pub const fn as_mut_slice(&mut self) -> &mut [T] {
-        self.vec.as_mut_slice()
+        self.inner.as_mut_slice()
     }
 
     #[inline]
     /// See [`Vec::as_ptr`].
     pub const fn as_ptr(&self) -> *const T {
-        self.vec.as_ptr()
+        self.inner.as_ptr()
     }
 
     /// See [`Vec::as_mut_ptr`].
     #[inline]
     pub const fn as_mut_ptr(&mut self) -> *mut T {
-        self.vec.as_mut_ptr()
+        self.inner.as_mut_ptr()
+    }
+
+    /// See [`slice::as_ptr_range`].
+    #[inline]
+    pub const fn as_ptr_range(&self) -> Range<*const T> {
+        self.inner.as_slice().as_ptr_range()
+    }
+
+    /// See [`slice::as_mut_ptr_range`].
+    #[inline]
+    pub const fn as_mut_ptr_range(&mut self) -> Range<*mut T> {
+        self.inner.as_mut_slice().as_mut_ptr_range()
+    }
+
+    /// Returns the elements as a slice of cells, allowing shared mutation, see [`Cell::as_slice_of_cells`].
+    ///
+    /// Takes `&mut self` since handing out cells from a shared reference would let other references observe the mutation.
+    #[inline]
+    pub fn as_slice_of_cells(&mut self) -> &[Cell<T>] {
+        Cell::from_mut(self.inner.as_mut_slice()).as_slice_of_cells()
+    }
+
+    /// Returns the first `M` elements, which are guaranteed to exist, as an array of cells, see
+    /// [`VecMin::as_slice_of_cells`].
+    #[inline]
+    pub fn min_cells(&mut self) -> &[Cell<T>; M] {
+        match self.as_slice_of_cells().first_chunk() {
+            Some(min) => min,
+            None => unreachable!(),
+        }
     }
 
     /// See [`Vec::spare_capacity_mut`].
     #[inline]
     pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
-        self.vec.spare_capacity_mut()
+        self.inner.spare_capacity_mut()
+    }
+}
+
+macro_rules! impl_read_int {
+    ($($int:ty => $le:ident, $be:ident;)*) => {$(
+        #[doc = concat!("Reads a little endian `", stringify!($int), "` at `OFFSET` within the guaranteed prefix.")]
+        ///
+        /// Fails to compile if the value does not fit within the first `M` bytes.
+        #[inline]
+        pub const fn $le<const OFFSET: usize>(&self) -> $int {
+            <$int>::from_le_bytes(*self.read_bytes::<OFFSET, { size_of::<$int>() }>())
+        }
+
+        #[doc = concat!("Reads a big endian `", stringify!($int), "` at `OFFSET` within the guaranteed prefix.")]
+        ///
+        /// Fails to compile if the value does not fit within the first `M` bytes.
+        #[inline]
+        pub const fn $be<const OFFSET: usize>(&self) -> $int {
+            <$int>::from_be_bytes(*self.read_bytes::<OFFSET, { size_of::<$int>() }>())
+        }
+    )*};
+}
+
+impl<const M: usize> VecMin<u8, M> {
+    /// Returns the `N` bytes at `OFFSET` within the guaranteed prefix.
+    ///
+    /// Fails to compile if the bytes do not fit within the first `M` bytes.
+    #[inline]
+    pub const fn read_bytes<const OFFSET: usize, const N: usize>(&self) -> &[u8; N] {
+        const {
+            assert!(
+                OFFSET <= M && N <= M - OFFSET,
+                "read must fit within the minimum length"
+            )
+        };
+
+        match self.min_slice().split_at(OFFSET).1.first_chunk() {
+            Some(bytes) => bytes,
+            None => unreachable!(),
+        }
+    }
+
+    impl_read_int! {
+        u16 => read_u16_le, read_u16_be;
+        u32 => read_u32_le, read_u32_be;
+        u64 => read_u64_le, read_u64_be;
+        i16 => read_i16_le, read_i16_be;
+        i32 => read_i32_le, read_i32_be;
+        i64 => read_i64_le, read_i64_be;
     }
 }
 
@@ -335,42 +779,42 @@ impl<T, const M: usize> Deref for VecMin<T, M> {
 
     #[inline]
     fn deref(&self) -> &Self::Target {
-        self.vec.deref()
+        self.inner.deref()
     }
 }
 
 impl<T, const M: usize> DerefMut for VecMin<T, M> {
     #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
-        self.vec.deref_mut()
+        self.inner.deref_mut()
     }
 }
 
 impl<T, const M: usize> AsRef<[T]> for VecMin<T, M> {
     #[inline]
     fn as_ref(&self) -> &[T] {
-        self.vec.as_ref()
+        self.inner.as_ref()
     }
 }
 
 impl<T, const M: usize> AsMut<[T]> for VecMin<T, M> {
     #[inline]
     fn as_mut(&mut self) -> &mut [T] {
-        self.vec.as_mut()
+        self.inner.as_mut()
     }
 }
 
 impl<T, const M: usize> Borrow<[T]> for VecMin<T, M> {
     #[inline]
     fn borrow(&self) -> &[T] {
-        self.vec.borrow()
+        self.inner.borrow()
     }
 }
 
 impl<T, const M: usize> BorrowMut<[T]> for VecMin<T, M> {
     #[inline]
     fn borrow_mut(&mut self) -> &mut [T] {
-        self.vec.borrow_mut()
+        self.inner.borrow_mut()
     }
 }
 
@@ -381,7 +825,7 @@ impl<T, const M: usize> IntoIterator for VecMin<T, M> {
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
-        self.vec.into_iter()
+        self.inner.into_iter()
     }
 }
 
@@ -391,7 +835,7 @@ impl<'a, T: 'a, const M: usize> IntoIterator for &'a VecMin<T, M> {
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
-        self.vec.as_slice().iter()
+        self.inner.as_slice().iter()
     }
 }
 
@@ -401,189 +845,504 @@ impl<'a, T: 'a, const M: usize> IntoIterator for &'a mut VecMin<T, M> {
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
-        self.vec.as_mut_slice().iter_mut()
+        self.inner.as_mut_slice().iter_mut()
     }
 }
 
-// --- Immutable Access ---
 impl<T, const M: usize> VecMin<T, M> {
-    /// See [`Vec::capacity`].
+    /// Returns an iterator over runs of consecutive equal elements, see [`slice::chunk_by`].
+    ///
+    /// Every run is non-empty, and at least one run is yielded when `M >= 1`.
     #[inline]
-    pub const fn capacity(&self) -> usize {
-        self.vec.capacity()
+    pub fn runs(&self) -> slice::ChunkBy<'_, T, fn(&T, &T) -> bool>
+    where
+        T: PartialEq,
+    {
+        self.inner.chunk_by(T::eq)
     }
 
-    /// See [`Vec::len`].
+    /// Returns an iterator over runs of consecutive elements for which `same_run` returns `true`, see [`slice::chunk_by`].
+    ///
+    /// Every run is non-empty, and at least one run is yielded when `M >= 1`.
     #[inline]
-    #[allow(clippy::len_without_is_empty)]
-    pub const fn len(&self) -> usize {
-        self.vec.len()
+    pub fn runs_by<F>(&self, same_run: F) -> slice::ChunkBy<'_, T, F>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        self.inner.chunk_by(same_run)
     }
-}
-
-// --- Mutable Access ---
-
-// -- Not Len Decreasing --
 
-// - Capacity -
-impl<T, const M: usize> VecMin<T, M> {
-    /// See [`Vec::reserve`].
+    /// Returns the first run of consecutive equal elements, which is guaranteed to exist when `M >= 1`.
+    ///
+    /// Fails to compile if `M` is 0.
     #[inline]
-    pub fn reserve(&mut self, additional: usize) {
-        self.vec.reserve(additional);
-    }
+    pub fn first_run(&self) -> &[T]
+    where
+        T: PartialEq,
+    {
+        const {
+            assert!(
+                M >= 1,
+                "`first_run` requires a minimum length of at least 1"
+            )
+        };
 
-    /// See [`Vec::reserve_exact`].
-    #[inline]
-    pub fn reserve_exact(&mut self, additional: usize) {
-        self.vec.reserve_exact(additional);
+        let first = &self.min_slice()[0];
+        let len = self.inner.iter().take_while(|x| *x == first).count();
+        &self.inner[..len]
     }
 
-    /// See [`Vec::try_reserve`].
+    /// Returns an iterator that repeats the elements endlessly, see [`Iterator::cycle`].
+    ///
+    /// The iterator always yields an element since the vector is non-empty, so it can be used for round-robin
+    /// selection without handling exhaustion. Fails to compile if `M` is 0.
     #[inline]
-    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
-        self.vec.try_reserve(additional)
-    }
+    pub fn iter_cycle(&self) -> Cycle<slice::Iter<'_, T>> {
+        const {
+            assert!(
+                M >= 1,
+                "`iter_cycle` requires a minimum length of at least 1"
+            )
+        };
 
-    /// See [`Vec::try_reserve_exact`].
-    #[inline]
-    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
-        self.vec.try_reserve_exact(additional)
+        self.inner.iter().cycle()
     }
+}
 
-    /// See [`Vec::shrink_to_fit`].
+impl<T, const M: usize> VecMin<T, M> {
+    /// Returns an iterator over the elements tagged [`MinElem::Required`](crate::iter::MinElem::Required) for the
+    /// first `M` elements and [`MinElem::Extra`](crate::iter::MinElem::Extra) for the rest.
     #[inline]
-    pub fn shrink_to_fit(&mut self) {
-        self.vec.shrink_to_fit();
+    pub fn iter_tagged(&self) -> IterTagged<'_, T, M> {
+        IterTagged {
+            iter: self.inner.iter().enumerate(),
+        }
     }
 
-    /// See [`Vec::shrink_to`].
+    /// Returns an iterator over mutable references to the elements, tagged like [`iter_tagged`](VecMin::iter_tagged).
     #[inline]
-    pub fn shrink_to(&mut self, min_capacity: usize) {
-        self.vec.shrink_to(min_capacity);
+    pub fn iter_tagged_mut(&mut self) -> IterTaggedMut<'_, T, M> {
+        IterTaggedMut {
+            iter: self.inner.iter_mut().enumerate(),
+        }
     }
 }
 
-// - Growth -
 impl<T, const M: usize> VecMin<T, M> {
-    /// See [`Vec::push`].
+    /// Returns the number of complete `K` element frames that are guaranteed to exist, which is `M / K`.
     #[inline]
-    pub fn push(&mut self, item: T) {
-        self.vec.push(item);
-    }
+    pub const fn min_frames<const K: usize>(&self) -> usize {
+        const { assert!(K > 0, "frame size must be non-zero") };
 
-    /// See [`Vec::insert`].
-    #[inline]
-    pub fn insert(&mut self, index: usize, element: T) {
-        self.vec.insert(index, element);
+        M / K
     }
 
-    /// See [`Vec::append`].
+    /// Returns an iterator over non-overlapping `K` element frames, see [`slice::chunks_exact`].
+    ///
+    /// At least [`min_frames`](VecMin::min_frames) frames are yielded. Elements after the last complete frame are
+    /// available from [`Frames::remainder`] or [`frames_remainder`](VecMin::frames_remainder).
     #[inline]
-    pub fn append(&mut self, other: &mut Vec<T>) {
-        self.vec.append(other);
+    pub fn frames<const K: usize>(&self) -> Frames<'_, T, K> {
+        const { assert!(K > 0, "frame size must be non-zero") };
+
+        Frames {
+            chunks: self.inner.chunks_exact(K),
+        }
     }
 
-    /// See [`Vec::extend_from_slice`].
+    /// Returns an iterator over non-overlapping mutable `K` element frames, see [`slice::chunks_exact_mut`].
+    ///
+    /// At least [`min_frames`](VecMin::min_frames) frames are yielded.
     #[inline]
-    pub fn extend_from_slice(&mut self, other: &[T])
-    where
-        T: Clone,
-    {
-        self.vec.extend_from_slice(other);
+    pub fn frames_mut<const K: usize>(&mut self) -> FramesMut<'_, T, K> {
+        const { assert!(K > 0, "frame size must be non-zero") };
+
+        FramesMut {
+            chunks: self.inner.chunks_exact_mut(K),
+        }
     }
 
-    /// See [`Vec::extend_from_within`].
+    /// Returns the elements after the last complete `K` element frame.
     #[inline]
-    pub fn extend_from_within<R>(&mut self, range: R)
-    where
-        R: RangeBounds<usize>,
-        T: Clone,
-    {
-        self.vec.extend_from_within(range);
+    pub fn frames_remainder<const K: usize>(&self) -> &[T] {
+        const { assert!(K > 0, "frame size must be non-zero") };
+
+        &self.inner[self.inner.len() / K * K..]
+    }
+
+    /// Moves the elements into `K` element frames and the elements after the last complete frame, consuming the vector.
+    ///
+    /// At least [`min_frames`](VecMin::min_frames) frames are returned. No element is cloned.
+    pub fn into_frames<const K: usize>(self) -> (Vec<[T; K]>, Vec<T>) {
+        const { assert!(K > 0, "frame size must be non-zero") };
+
+        let count = self.inner.len() / K;
+        let mut iter = self.inner.into_iter();
+        let frames = (0..count)
+            .map(|_| {
+                core::array::from_fn(|_| match iter.next() {
+                    Some(item) => item,
+                    None => unreachable!(),
+                })
+            })
+            .collect();
+
+        (frames, iter.collect())
+    }
+
+    /// Returns the first `K` element frame, which is guaranteed to exist.
+    ///
+    /// Fails to compile if `K` is 0 or greater than `M`.
+    #[inline]
+    pub const fn first_frame<const K: usize>(&self) -> &[T; K] {
+        const {
+            assert!(
+                K > 0 && K <= M,
+                "frame size must be non-zero and at most the minimum length"
+            )
+        };
+
+        match self.min_slice().first_chunk() {
+            Some(frame) => frame,
+            None => unreachable!(),
+        }
     }
 }
 
-impl<T, const M: usize> Extend<T> for VecMin<T, M> {
+impl<T, const M: usize> VecMin<T, M> {
+    /// Returns the index of the minimum element, which is guaranteed to exist. If several elements are equally minimum,
+    /// the index of the first is returned, like [`Iterator::min`].
+    ///
+    /// Fails to compile if `M` is 0.
     #[inline]
-    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
-        self.vec.extend(iter);
+    pub fn position_min(&self) -> usize
+    where
+        T: Ord,
+    {
+        self.position_min_by_key(|x| x)
     }
-}
 
-impl<'a, T: Copy, const M: usize> Extend<&'a T> for VecMin<T, M> {
+    /// Returns the index of the maximum element, which is guaranteed to exist. If several elements are equally maximum,
+    /// the index of the last is returned, like [`Iterator::max`].
+    ///
+    /// Fails to compile if `M` is 0.
     #[inline]
-    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
-        self.vec.extend(iter);
+    pub fn position_max(&self) -> usize
+    where
+        T: Ord,
+    {
+        self.position_max_by_key(|x| x)
+    }
+
+    /// Returns the index of the element with the minimum key, see [`position_min`](VecMin::position_min).
+    ///
+    /// Fails to compile if `M` is 0.
+    pub fn position_min_by_key<'a, K, F>(&'a self, mut key: F) -> usize
+    where
+        K: Ord,
+        F: FnMut(&'a T) -> K,
+    {
+        const {
+            assert!(
+                M >= 1,
+                "`position_min` requires a minimum length of at least 1"
+            )
+        };
+
+        match self.inner.iter().enumerate().min_by_key(|(_, x)| key(x)) {
+            Some((index, _)) => index,
+            None => unreachable!(),
+        }
+    }
+
+    /// Returns the index of the element with the maximum key, see [`position_max`](VecMin::position_max).
+    ///
+    /// Fails to compile if `M` is 0.
+    pub fn position_max_by_key<'a, K, F>(&'a self, mut key: F) -> usize
+    where
+        K: Ord,
+        F: FnMut(&'a T) -> K,
+    {
+        const {
+            assert!(
+                M >= 1,
+                "`position_max` requires a minimum length of at least 1"
+            )
+        };
+
+        match self.inner.iter().enumerate().max_by_key(|(_, x)| key(x)) {
+            Some((index, _)) => index,
+            None => unreachable!(),
+        }
     }
 }
 
-// -- Len Decreasing --
 impl<T, const M: usize> VecMin<T, M> {
-    /// See [`Vec::pop`]. Pops an element from the vector if the length of the vector is greater than `M`, otherwise does nothing and returns `None`.
-    #[inline]
-    pub fn pop_to_min(&mut self) -> Option<T> {
-        if self.vec.len() > M {
-            self.vec.pop()
-        } else {
-            None
+    /// Groups the elements by the key returned by `key` into a `BTreeMap`, keeping the order of the elements within
+    /// each group. Every group is non-empty by construction.
+    pub fn group_by_key_btree<K, F>(self, mut key: F) -> BTreeMap<K, VecOne<T>>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let mut groups = BTreeMap::<K, VecOne<T>>::new();
+        for item in self.inner {
+            match groups.entry(key(&item)) {
+                btree_map::Entry::Occupied(mut group) => group.get_mut().push(item),
+                btree_map::Entry::Vacant(group) => {
+                    group.insert(VecOne::singleton(item));
+                }
+            }
         }
+        groups
+    }
+}
+
+// --- Immutable Access ---
+/// A summary of the memory used by a vector, see [`VecMin::mem_stats`].
+///
+/// Stats of many vectors can be added up with [`Iterator::sum`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MemStats {
+    /// The number of elements.
+    pub len: usize,
+    /// The number of elements that fit in the allocation.
+    pub capacity: usize,
+    /// The number of elements that fit in the allocation after the last element, see [`Vec::spare_capacity_mut`].
+    pub spare_capacity: usize,
+    /// The size of the allocation in bytes.
+    pub bytes: usize,
+}
+
+impl Sum for MemStats {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, stats| Self {
+            len: acc.len.saturating_add(stats.len),
+            capacity: acc.capacity.saturating_add(stats.capacity),
+            spare_capacity: acc.spare_capacity.saturating_add(stats.spare_capacity),
+            bytes: acc.bytes.saturating_add(stats.bytes),
+        })
     }
+}
 
-    /// See [`Vec::pop_if`]. Pops an element from the vector if the length of the vector is greater than `M` and the provided predicate returns `true`, otherwise does nothing and returns `None`.
+impl<T, const M: usize> VecMin<T, M> {
+    /// See [`Vec::capacity`].
     #[inline]
-    pub fn pop_to_min_if(&mut self, pred: impl FnOnce(&mut T) -> bool) -> Option<T> {
-        if self.vec.len() > M {
-            self.vec.pop_if(pred)
-        } else {
-            None
-        }
+    pub const fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    /// See [`Vec::len`].
+    #[inline]
+    #[allow(clippy::len_without_is_empty)]
+    pub const fn len(&self) -> usize {
+        self.inner.len()
     }
 
-    /// See [`Vec::remove`]. Returns an error if the operation would reduce the length of the vector below `M`.
+    /// Returns the size of the heap allocation of the vector in bytes, which is 0 for zero sized types.
     #[inline]
-    #[must_use = "this operation may fail"]
-    pub fn remove(&mut self, index: usize) -> Result<T, ModifyError<M>> {
-        if self.vec.len() > M {
-            Ok(self.vec.remove(index))
-        } else {
-            Err(ModifyError)
+    pub const fn allocated_bytes(&self) -> usize {
+        self.inner.capacity() * size_of::<T>()
+    }
+
+    /// Returns a summary of the memory used by the vector.
+    #[inline]
+    pub const fn mem_stats(&self) -> MemStats {
+        MemStats {
+            len: self.inner.len(),
+            capacity: self.inner.capacity(),
+            spare_capacity: self.inner.capacity() - self.inner.len(),
+            bytes: self.allocated_bytes(),
         }
     }
+}
+
+// --- Mutable Access ---
+
+// -- Not Len Decreasing --
 
-    /// See [`Vec::swap_remove`]. Returns an error if the operation would reduce the length of the vector below `M`.
+// - Capacity -
+impl<T, const M: usize> VecMin<T, M> {
+    /// See [`Vec::reserve`].
     #[inline]
-    #[must_use = "this operation may fail"]
-    pub fn swap_remove(&mut self, index: usize) -> Result<T, ModifyError<M>> {
-        if self.vec.len() > M {
-            Ok(self.vec.swap_remove(index))
-        } else {
-            Err(ModifyError)
+    pub fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional);
+    }
+
+    /// See [`Vec::reserve_exact`].
+    #[inline]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.inner.reserve_exact(additional);
+    }
+
+    /// See [`Vec::try_reserve`].
+    #[inline]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.inner.try_reserve(additional)
+    }
+
+    /// See [`Vec::try_reserve_exact`].
+    #[inline]
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.inner.try_reserve_exact(additional)
+    }
+
+    /// See [`Vec::shrink_to_fit`].
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.inner.shrink_to_fit();
+    }
+
+    /// See [`Vec::shrink_to`].
+    #[inline]
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.inner.shrink_to(min_capacity);
+    }
+}
+
+// - Overwrite -
+impl<T, const M: usize> VecMin<T, M> {
+    /// See [`slice::copy_from_slice`].
+    ///
+    /// # Panics
+    /// Panics if the length of `src` is not the length of the vector.
+    #[inline]
+    #[track_caller]
+    pub fn copy_from_slice(&mut self, src: &[T])
+    where
+        T: Copy,
+    {
+        self.inner.copy_from_slice(src);
+    }
+
+    /// See [`slice::swap_with_slice`].
+    ///
+    /// # Panics
+    /// Panics if the length of `other` is not the length of the vector.
+    #[inline]
+    #[track_caller]
+    pub fn swap_with_slice(&mut self, other: &mut [T]) {
+        self.inner.swap_with_slice(other);
+    }
+
+    /// Overwrites the first `M` elements of the vector, which are guaranteed to exist, with `src`.
+    #[inline]
+    pub fn copy_min_from(&mut self, src: &[T; M])
+    where
+        T: Copy,
+    {
+        *self.min_slice_mut() = *src;
+    }
+}
+
+// - Growth -
+impl<T, const M: usize> VecMin<T, M> {
+    /// Pushes the results of calling `f` until the length is at least `M`, doing nothing if it already is.
+    ///
+    /// Restores the minimum length after the vector was shortened through [`VecMin::as_mut_vec`].
+    #[inline]
+    pub fn ensure_min_with(&mut self, f: impl FnMut() -> T) {
+        if self.inner.len() < M {
+            self.inner.resize_with(M, f);
         }
     }
 
-    /// See [`Vec::truncate`]. Returns an error if the operation would reduce the length of the vector below `M`.
+    /// See [`Vec::push`].
     #[inline]
-    #[must_use = "this operation may fail"]
-    pub fn truncate(&mut self, len: usize) -> Result<(), ModifyError<M>> {
-        if len >= M {
-            self.vec.truncate(len);
-            Ok(())
-        } else {
-            Err(ModifyError)
+    pub fn push(&mut self, item: T) {
+        self.inner.push(item);
+    }
+
+    /// See [`Vec::insert`].
+    #[inline]
+    pub fn insert(&mut self, index: usize, element: T) {
+        self.inner.insert(index, element);
+    }
+
+    /// See [`Vec::append`].
+    #[inline]
+    pub fn append(&mut self, other: &mut Vec<T>) {
+        self.inner.append(other);
+    }
+
+    /// See [`Vec::extend_from_slice`].
+    #[inline]
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.inner.extend_from_slice(other);
+    }
+
+    /// See [`Vec::extend_from_within`].
+    #[inline]
+    pub fn extend_from_within<R>(&mut self, range: R)
+    where
+        R: RangeBounds<usize>,
+        T: Clone,
+    {
+        self.inner.extend_from_within(range);
+    }
+
+    /// Appends clones of the elements of every slice in `slices`, reserving the total length once up front.
+    ///
+    /// # Panics
+    /// Panics if the total length overflows `usize`.
+    #[track_caller]
+    pub fn extend_from_slices(&mut self, slices: &[&[T]])
+    where
+        T: Clone,
+    {
+        let additional = slices
+            .iter()
+            .try_fold(0usize, |len, slice| len.checked_add(slice.len()))
+            .expect("capacity overflow");
+
+        self.inner.reserve(additional);
+        for slice in slices {
+            self.inner.extend_from_slice(slice);
         }
     }
+}
+
+impl<T, const M: usize> Extend<T> for VecMin<T, M> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.inner.extend(iter);
+    }
+}
+
+impl<'a, T: Copy, const M: usize> Extend<&'a T> for VecMin<T, M> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.inner.extend(iter);
+    }
+}
+
+// -- Len Decreasing --
+impl<T, const M: usize> VecMin<T, M> {
+    /// Truncates to `M` and shrinks the capacity as close to `M` as the allocator allows.
+    #[inline]
+    pub fn shrink_to_min(&mut self) {
+        self.shrink_to_min_with_headroom(0);
+    }
 
-    /// See [`Vec::truncate`]. Truncates the vector to `len` if `len` is greater than or equal to `M`, otherwise truncates the vector to `M`.
+    /// Truncates to `M` and shrinks the capacity to `M + headroom`, keeping room for that many elements to be added
+    /// without reallocating. Does nothing to the capacity if it is already lower.
     #[inline]
-    pub fn truncate_or_min(&mut self, len: usize) {
-        self.vec.truncate(len.max(M))
+    pub fn shrink_to_min_with_headroom(&mut self, headroom: usize) {
+        self.inner.truncate(M);
+        self.inner.shrink_to(M.saturating_add(headroom));
     }
 
-    /// See [`Vec::truncate`]. Truncates the vector to `M`.
+    /// See [`Vec::pop_if`]. Pops an element from the vector if the length of the vector is greater than `M` and the provided predicate returns `true`, otherwise does nothing and returns `None`.
     #[inline]
-    pub fn truncate_to_min(&mut self) {
-        self.vec.truncate(M);
+    pub fn pop_to_min_if(&mut self, pred: impl FnOnce(&mut T) -> bool) -> Option<T> {
+        if self.inner.len() > M {
+            self.inner.pop_if(pred)
+        } else {
+            None
+        }
     }
 
     /// See [`Vec::resize`]. Returns an error if the operation would reduce the length of the vector below `M`.
@@ -594,10 +1353,10 @@ impl<T, const M: usize> VecMin<T, M> {
         T: Clone,
     {
         if new_len >= M {
-            self.vec.resize(new_len, value);
+            self.inner.resize(new_len, value);
             Ok(())
         } else {
-            Err(ModifyError)
+            ModifyError::fail()
         }
     }
 
@@ -607,7 +1366,7 @@ impl<T, const M: usize> VecMin<T, M> {
     where
         T: Clone,
     {
-        self.vec.resize(new_len.max(M), value);
+        self.inner.resize(new_len.max(M), value);
     }
 
     /// See [`Vec::resize_with`]. Returns an error if the operation would reduce the length of the vector below `M`.
@@ -618,10 +1377,10 @@ impl<T, const M: usize> VecMin<T, M> {
         F: FnMut() -> T,
     {
         if new_len >= M {
-            self.vec.resize_with(new_len, generator);
+            self.inner.resize_with(new_len, generator);
             Ok(())
         } else {
-            Err(ModifyError)
+            ModifyError::fail()
         }
     }
 
@@ -631,23 +1390,80 @@ impl<T, const M: usize> VecMin<T, M> {
     where
         F: FnMut() -> T,
     {
-        self.vec.resize_with(new_len.max(M), generator);
+        self.inner.resize_with(new_len.max(M), generator);
     }
 
     /// See [`Vec::drain`]. Returns an error if the operation would reduce the length of the vector below `M`.
+    ///
+    /// Leaking the returned `Drain` (e.g. with [`mem::forget`](core::mem::forget)) leaves at least `M` elements in the
+    /// vector. `Vec` truncates to the start of the drained range until the `Drain` is dropped, so ranges starting before
+    /// `M` are first rotated to the end of the vector, which costs a move of every element after the range start.
     #[must_use = "this operation may fail"]
     pub fn drain<R>(&mut self, range: R) -> Result<vec::Drain<'_, T>, ModifyError<M>>
     where
         R: RangeBounds<usize>,
     {
-        let drain_len = slice_range(&range, ..self.vec.len()).len();
-        let final_len = self.vec.len() - drain_len;
+        let range = slice_range(&range, ..self.inner.len());
+        let final_len = self.inner.len() - range.len();
+
+        if final_len < M {
+            return ModifyError::fail();
+        }
 
-        if final_len >= M {
-            Ok(self.vec.drain(range))
+        if range.start < M {
+            // Move the drained elements to the end, keeping the order of both the kept and drained elements.
+            self.inner[range.start..].rotate_left(range.len());
+            Ok(self.inner.drain(final_len..))
         } else {
-            Err(ModifyError)
+            Ok(self.inner.drain(range))
+        }
+    }
+
+    /// See [`Vec::drain`]. Removes `range` and returns the removed elements as a `VecMin` with a minimum length of `K`.
+    /// Returns an error and leaves the vector untouched if the operation would reduce the length of the vector below
+    /// `M`, or if the range has less than `K` elements.
+    #[must_use = "this operation may fail"]
+    pub fn drain_min<const K: usize, R>(
+        &mut self,
+        range: R,
+    ) -> Result<VecMin<T, K>, DrainMinError<M, K>>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = slice_range(&range, ..self.inner.len());
+        if range.len() < K {
+            return Err(DrainMinError::TooShort(range.len()));
+        }
+
+        let drained = self.drain(range)?.collect();
+        // Invariant: We checked that the range had at least `K` elements.
+        Ok(VecMin::from_inner(drained))
+    }
+
+    /// See [`Vec::splice`]. Replaces `range` with clones of `replacement` and returns the removed elements. Returns an
+    /// error if the operation would reduce the length of the vector below `M`.
+    ///
+    /// Unlike `splice` the replacement happens eagerly, the elements of `replacement` are cloned before the vector is
+    /// modified so a panicking `Clone` leaves it untouched.
+    #[must_use = "this operation may fail"]
+    pub fn replace_range<R>(
+        &mut self,
+        range: R,
+        replacement: &[T],
+    ) -> Result<Vec<T>, ModifyError<M>>
+    where
+        R: RangeBounds<usize>,
+        T: Clone,
+    {
+        let range = slice_range(&range, ..self.inner.len());
+        let final_len = self.inner.len() - range.len() + replacement.len();
+
+        if final_len < M {
+            return ModifyError::fail();
         }
+
+        let replacement = replacement.to_vec();
+        Ok(self.inner.splice(range, replacement).collect())
     }
 
     /// See [`Vec::split_off`]. Returns an error if the operation would reduce the length of the vector below `M`.
@@ -655,68 +1471,1314 @@ impl<T, const M: usize> VecMin<T, M> {
     #[must_use = "this operation may fail"]
     pub fn split_off(&mut self, at: usize) -> Result<Vec<T>, ModifyError<M>> {
         if at >= M {
-            Ok(self.vec.split_off(at))
+            Ok(self.inner.split_off(at))
         } else {
-            Err(ModifyError)
+            ModifyError::fail()
         }
     }
-}
 
-// --- Equality & Ordering ---
-impl<T: PartialEq, const M1: usize, const M2: usize> PartialEq<VecMin<T, M2>> for VecMin<T, M1> {
+    /// See [`Vec::split_off`]. Splits off every element after the first `M`, leaving the vector at its minimum length.
     #[inline]
-    fn eq(&self, other: &VecMin<T, M2>) -> bool {
-        self.as_slice() == other.as_slice()
+    pub fn split_off_min(&mut self) -> Vec<T> {
+        self.inner.split_off(M)
     }
-}
-
-impl<T: Eq, const M: usize> Eq for VecMin<T, M> {}
 
-impl<T: PartialEq, const M: usize> PartialEq<Vec<T>> for VecMin<T, M> {
+    /// See [`Vec::retain`]. Returns an error and leaves the vector untouched if the operation would reduce the length of
+    /// the vector below `M`.
+    ///
+    /// The predicate is called exactly once for every element, in order, before any element is removed.
     #[inline]
-    fn eq(&self, other: &Vec<T>) -> bool {
-        self.as_slice() == other.as_slice()
+    #[must_use = "this operation may fail"]
+    pub fn retain<F>(&mut self, keep: F) -> Result<(), ModifyError<M>>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mask = self.inner.iter().map(keep).collect();
+        self.retain_mask(mask)
     }
-}
 
-impl<T: PartialEq, const M: usize> PartialEq<VecMin<T, M>> for Vec<T> {
-    #[inline]
-    fn eq(&self, other: &VecMin<T, M>) -> bool {
-        self.as_slice() == other.as_slice()
-    }
-}
+    /// See [`Vec::retain`]. Removes the elements for which `keep` returns `false` in order until the length of the vector
+    /// reaches `M`, the elements after that point are kept without calling the predicate.
+    pub fn retain_to_min<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut removable = self.inner.len() - M;
+        self.inner.retain(|item| {
+            if removable == 0 || keep(item) {
+                true
+            } else {
+                removable -= 1;
+                false
+            }
+        });
+    }
+
+    /// Like [`VecMin::retain`], but fills the gap of each removed element with the last element instead of shifting every
+    /// element after it, so the order of the kept elements is not preserved. Returns an error and leaves the vector
+    /// untouched if the operation would reduce the length of the vector below `M`.
+    ///
+    /// The predicate is called exactly once for every element, in order, before any element is removed.
+    #[must_use = "this operation may fail"]
+    pub fn swap_retain<F>(&mut self, keep: F) -> Result<(), ModifyError<M>>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut mask: Vec<bool> = self.inner.iter().map(keep).collect();
+        if mask.iter().filter(|keep| **keep).count() < M {
+            return ModifyError::fail();
+        }
 
-impl<T: PartialOrd, const M1: usize, const M2: usize> PartialOrd<VecMin<T, M2>> for VecMin<T, M1> {
-    #[inline]
-    fn partial_cmp(&self, other: &VecMin<T, M2>) -> Option<Ordering> {
-        self.as_slice().partial_cmp(other.as_slice())
+        let mut i = 0;
+        while i < mask.len() {
+            if mask[i] {
+                i += 1;
+            } else {
+                mask.swap_remove(i);
+                self.inner.swap_remove(i);
+            }
+        }
+        Ok(())
     }
-}
 
-impl<T: Ord, const M: usize> Ord for VecMin<T, M> {
-    #[inline]
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.as_slice().cmp(other.as_slice())
+    /// Like [`VecMin::retain_to_min`], but fills the gap of each removed element with the last element instead of
+    /// shifting every element after it, so the order of the kept elements is not preserved.
+    pub fn swap_retain_to_min<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut i = 0;
+        while i < self.inner.len() && self.inner.len() > M {
+            if keep(&self.inner[i]) {
+                i += 1;
+            } else {
+                self.inner.swap_remove(i);
+            }
+        }
     }
-}
 
-impl<T: PartialOrd, const M: usize> PartialOrd<Vec<T>> for VecMin<T, M> {
+    /// See [`Vec::extract_if`]. Returns an iterator removing and yielding the elements in `range` for which `filter`
+    /// returns `true`, until the length of the vector reaches `M`. The elements after that point are not tested.
+    ///
+    /// Like `Vec::extract_if`, the kept elements are moved back over the removed ones in a single pass, and dropping the
+    /// iterator keeps the elements that have not been yielded. Leaking it (e.g. with
+    /// [`mem::forget`](core::mem::forget)) leaves at least `M` elements in the vector but may leak the rest. With the
+    /// `vecmin_no_unsafe` cfg, elements are instead removed one at a time, each removal shifting the elements after it.
+    ///
+    /// # Panics
+    /// Panics if the range is out of bounds.
+    #[track_caller]
+    pub fn extract_if_min<F, R>(&mut self, range: R, filter: F) -> ExtractIfMin<'_, T, F, M>
+    where
+        F: FnMut(&mut T) -> bool,
+        R: RangeBounds<usize>,
+    {
+        let range = slice_range(&range, ..self.inner.len());
+        ExtractIfMin {
+            old_len: self.inner.len(),
+            vec: &mut self.inner,
+            kept: range.start,
+            removed: 0,
+            end: range.end,
+            filter,
+        }
+    }
+
+    /// See [`Vec::retain`]. Removes the elements for which `keep` returns `false` and returns them in order. Returns an
+    /// error and leaves the vector untouched if the operation would reduce the length of the vector below `M`.
+    ///
+    /// The predicate is called exactly once for every element, in order.
+    #[must_use = "this operation may fail"]
+    pub fn retain_drain<F>(&mut self, mut keep: F) -> Result<Vec<T>, ModifyError<M>>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mask: Vec<bool> = self.inner.iter().map(&mut keep).collect();
+
+        if mask.iter().filter(|keep| **keep).count() < M {
+            return ModifyError::fail();
+        }
+
+        let mut mask = mask.into_iter();
+        Ok(self
+            .inner
+            .extract_if(.., |_| !mask.next().unwrap_or(true))
+            .collect())
+    }
+
+    /// See [`Vec::dedup`]. Returns an error and leaves the vector at its length if the operation would reduce the length
+    /// of the vector below `M`.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn dedup(&mut self) -> Result<(), ModifyError<M>>
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b)
+    }
+
+    /// See [`Vec::dedup_by_key`]. Returns an error and leaves the vector at its length if the operation would reduce the
+    /// length of the vector below `M`.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F) -> Result<(), ModifyError<M>>
+    where
+        K: PartialEq,
+        F: FnMut(&mut T) -> K,
+    {
+        self.dedup_by(|a, b| key(a) == key(b))
+    }
+
+    /// See [`Vec::dedup_by`]. Returns an error and leaves the vector at its length if the operation would reduce the
+    /// length of the vector below `M`.
+    ///
+    /// Every comparison is made before any element is removed, elements mutated by `same_bucket` stay mutated when an
+    /// error is returned.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn dedup_by<F>(&mut self, same_bucket: F) -> Result<(), ModifyError<M>>
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        let mask = self.dedup_mask(usize::MAX, same_bucket);
+        self.retain_mask(mask)
+    }
+
+    /// See [`Vec::dedup`]. Removes consecutive duplicates in order until the length of the vector reaches `M`, the
+    /// elements after that point are kept without being compared.
+    #[inline]
+    pub fn dedup_to_min(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by_to_min(|a, b| a == b);
+    }
+
+    /// See [`Vec::dedup_by_key`]. Removes consecutive elements with the same key in order until the length of the
+    /// vector reaches `M`, the elements after that point are kept without being compared.
+    #[inline]
+    pub fn dedup_by_key_to_min<K, F>(&mut self, mut key: F)
+    where
+        K: PartialEq,
+        F: FnMut(&mut T) -> K,
+    {
+        self.dedup_by_to_min(|a, b| key(a) == key(b));
+    }
+
+    /// See [`Vec::dedup_by`]. Removes consecutive elements in the same bucket in order until the length of the vector
+    /// reaches `M`, the elements after that point are kept without being compared.
+    #[inline]
+    pub fn dedup_by_to_min<F>(&mut self, same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        let mask = self.dedup_mask(self.inner.len() - M, same_bucket);
+        match self.retain_mask(mask) {
+            Ok(()) => {}
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Removes every element equal to an earlier element, keeping the first occurrences in order. Returns an error and
+    /// leaves the vector untouched if the operation would reduce the length of the vector below `M`.
+    ///
+    /// Unlike `dedup` the duplicates do not need to be consecutive.
+    #[must_use = "this operation may fail"]
+    pub fn unique(&mut self) -> Result<(), ModifyError<M>>
+    where
+        T: Ord,
+    {
+        let mask = first_occurrences(self.inner.iter());
+        self.retain_mask(mask)
+    }
+
+    /// Removes every element with the same key as an earlier element, keeping the first occurrences in order. Returns
+    /// an error and leaves the vector untouched if the operation would reduce the length of the vector below `M`.
+    #[must_use = "this operation may fail"]
+    pub fn unique_by_key<K, F>(&mut self, key: F) -> Result<(), ModifyError<M>>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let mask = first_occurrences(self.inner.iter().map(key));
+        self.retain_mask(mask)
+    }
+
+    /// Removes every element equal to an earlier element like [`VecMin::unique`], except that the earliest duplicates
+    /// are kept if needed to keep the length at least `M`.
+    pub fn unique_to_min(&mut self)
+    where
+        T: Ord,
+    {
+        let mut mask = first_occurrences(self.inner.iter());
+        let mut missing = M.saturating_sub(mask.iter().filter(|keep| **keep).count());
+        for keep in mask.iter_mut().filter(|keep| !**keep) {
+            if missing == 0 {
+                break;
+            }
+            *keep = true;
+            missing -= 1;
+        }
+
+        match self.retain_mask(mask) {
+            Ok(()) => {}
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Returns whether each element is kept by `dedup_by`, marking at most `limit` elements as removed.
+    fn dedup_mask<F>(&mut self, mut limit: usize, mut same_bucket: F) -> Vec<bool>
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        let mut mask = Vec::with_capacity(self.inner.len());
+        let mut last_kept = 0;
+        for i in 0..self.inner.len() {
+            let (kept, rest) = self.inner.split_at_mut(i);
+            let keep = match kept.get_mut(last_kept) {
+                Some(last) if limit > 0 => !same_bucket(&mut rest[0], last),
+                _ => true,
+            };
+
+            if keep {
+                last_kept = i;
+            } else {
+                limit -= 1;
+            }
+            mask.push(keep);
+        }
+        mask
+    }
+
+    fn retain_mask(&mut self, mask: Vec<bool>) -> Result<(), ModifyError<M>> {
+        if mask.iter().filter(|keep| **keep).count() < M {
+            return ModifyError::fail();
+        }
+
+        let mut mask = mask.into_iter();
+        self.inner.retain(|_| mask.next().unwrap_or(true));
+        Ok(())
+    }
+}
+
+/// Returns whether each key is the first occurrence of that key.
+fn first_occurrences<K: Ord>(keys: impl Iterator<Item = K>) -> Vec<bool> {
+    let mut seen = BTreeSet::new();
+    keys.map(|key| seen.insert(key)).collect()
+}
+
+// --- Equality & Ordering ---
+impl<T: PartialEq, const M1: usize, const M2: usize> PartialEq<VecMin<T, M2>> for VecMin<T, M1> {
+    #[inline]
+    fn eq(&self, other: &VecMin<T, M2>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const M: usize> Eq for VecMin<T, M> {}
+
+impl<T: PartialEq, const M: usize> PartialEq<Vec<T>> for VecMin<T, M> {
+    #[inline]
+    fn eq(&self, other: &Vec<T>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq, const M: usize> PartialEq<VecMin<T, M>> for Vec<T> {
+    #[inline]
+    fn eq(&self, other: &VecMin<T, M>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq, const M: usize> PartialEq<[T]> for VecMin<T, M> {
+    #[inline]
+    fn eq(&self, other: &[T]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq, const M: usize> PartialEq<VecMin<T, M>> for [T] {
+    #[inline]
+    fn eq(&self, other: &VecMin<T, M>) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<T: PartialEq, const M: usize> PartialEq<&[T]> for VecMin<T, M> {
+    #[inline]
+    fn eq(&self, other: &&[T]) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl<T: PartialEq, const M: usize> PartialEq<VecMin<T, M>> for &[T] {
+    #[inline]
+    fn eq(&self, other: &VecMin<T, M>) -> bool {
+        *self == other.as_slice()
+    }
+}
+
+impl<T: PartialEq, const M: usize> PartialEq<&mut [T]> for VecMin<T, M> {
+    #[inline]
+    fn eq(&self, other: &&mut [T]) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl<T: PartialEq, const M: usize> PartialEq<VecMin<T, M>> for &mut [T] {
+    #[inline]
+    fn eq(&self, other: &VecMin<T, M>) -> bool {
+        *self == other.as_slice()
+    }
+}
+
+impl<T: PartialEq, const N: usize, const M: usize> PartialEq<[T; N]> for VecMin<T, M> {
+    #[inline]
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq, const N: usize, const M: usize> PartialEq<VecMin<T, M>> for [T; N] {
+    #[inline]
+    fn eq(&self, other: &VecMin<T, M>) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<T: PartialEq, const N: usize, const M: usize> PartialEq<&[T; N]> for VecMin<T, M> {
+    #[inline]
+    fn eq(&self, other: &&[T; N]) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl<T: PartialEq, const N: usize, const M: usize> PartialEq<VecMin<T, M>> for &[T; N] {
+    #[inline]
+    fn eq(&self, other: &VecMin<T, M>) -> bool {
+        *self == other.as_slice()
+    }
+}
+
+impl<T: PartialOrd, const M1: usize, const M2: usize> PartialOrd<VecMin<T, M2>> for VecMin<T, M1> {
+    #[inline]
+    fn partial_cmp(&self, other: &VecMin<T, M2>) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Ord, const M: usize> Ord for VecMin<T, M> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T: PartialOrd, const M: usize> PartialOrd<Vec<T>> for VecMin<T, M> {
     #[inline]
     fn partial_cmp(&self, other: &Vec<T>) -> Option<Ordering> {
         self.as_slice().partial_cmp(other.as_slice())
     }
 }
 
-impl<T: PartialOrd, const M: usize> PartialOrd<VecMin<T, M>> for Vec<T> {
-    #[inline]
-    fn partial_cmp(&self, other: &VecMin<T, M>) -> Option<Ordering> {
-        self.as_slice().partial_cmp(other.as_slice())
+impl<T: PartialOrd, const M: usize> PartialOrd<VecMin<T, M>> for Vec<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &VecMin<T, M>) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+mod __arbitrary {
+    use arbitrary::{Arbitrary, Result, Unstructured, size_hint};
+
+    use super::*;
+
+    impl<'a, T: Arbitrary<'a>, const M: usize> Arbitrary<'a> for VecMin<T, M> {
+        /// Generates the `M` minimum elements, then an arbitrary number of extra elements.
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            let head = <[T; M]>::arbitrary(u)?;
+            let tail = Vec::arbitrary(u)?;
+            Ok(Self::from_head_tail(head, tail))
+        }
+
+        fn arbitrary_take_rest(mut u: Unstructured<'a>) -> Result<Self> {
+            let head = <[T; M]>::arbitrary(&mut u)?;
+            let tail = Vec::arbitrary_take_rest(u)?;
+            Ok(Self::from_head_tail(head, tail))
+        }
+
+        #[inline]
+        fn size_hint(depth: usize) -> (usize, Option<usize>) {
+            size_hint::and(<[T; M]>::size_hint(depth), (0, None))
+        }
+    }
+}
+
+#[cfg(feature = "arrow")]
+mod __arrow {
+    use arrow_array::{Array, ArrowPrimitiveType, PrimitiveArray};
+    use arrow_buffer::{ArrowNativeType, ScalarBuffer};
+
+    use super::*;
+
+    impl<T: ArrowNativeType, const M: usize> From<VecMin<T, M>> for ScalarBuffer<T> {
+        /// Converts without copying, the buffer takes ownership of the allocation.
+        #[inline]
+        fn from(vec_min: VecMin<T, M>) -> Self {
+            vec_min.inner.into()
+        }
+    }
+
+    impl<T: ArrowNativeType, const M: usize> TryFrom<ScalarBuffer<T>> for VecMin<T, M> {
+        type Error = ScalarBuffer<T>;
+
+        /// Reuses the allocation if the buffer is not shared or offset, otherwise copies the elements. Returns the
+        /// buffer back as an error if its length is less than `M`.
+        #[inline]
+        fn try_from(buffer: ScalarBuffer<T>) -> Result<Self, Self::Error> {
+            if buffer.len() >= M {
+                // Invariant: We just checked that the length was at least `M`.
+                Ok(Self::from_inner(buffer.into()))
+            } else {
+                Err(buffer)
+            }
+        }
+    }
+
+    impl<A: ArrowPrimitiveType, const M: usize> From<VecMin<A::Native, M>> for PrimitiveArray<A> {
+        /// Converts without copying into an array with no nulls.
+        #[inline]
+        fn from(vec_min: VecMin<A::Native, M>) -> Self {
+            PrimitiveArray::new(vec_min.into(), None)
+        }
+    }
+
+    impl<A: ArrowPrimitiveType, const M: usize> TryFrom<PrimitiveArray<A>> for VecMin<A::Native, M> {
+        type Error = PrimitiveArray<A>;
+
+        /// Reuses the allocation of the values if possible, otherwise copies them. Returns the array back as an error
+        /// if it contains nulls or its length is less than `M`.
+        #[inline]
+        fn try_from(array: PrimitiveArray<A>) -> Result<Self, Self::Error> {
+            if array.len() >= M && array.null_count() == 0 {
+                let (_, values, _) = array.into_parts();
+
+                // Invariant: We just checked that the length was at least `M`.
+                Ok(Self::from_inner(values.into()))
+            } else {
+                Err(array)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "bincode")]
+mod __bincode {
+    use alloc::format;
+
+    use bincode::de::{BorrowDecoder, Decoder};
+    use bincode::enc::Encoder;
+    use bincode::error::{DecodeError, EncodeError};
+    use bincode::{BorrowDecode, Decode, Encode};
+
+    use super::*;
+
+    impl<T: Encode, const M: usize> Encode for VecMin<T, M> {
+        /// Encodes the vector like a `Vec`.
+        #[inline]
+        fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+            self.inner.encode(encoder)
+        }
+    }
+
+    impl<Context, T: Decode<Context>, const M: usize> Decode<Context> for VecMin<T, M> {
+        /// Decodes the vector like a `Vec`, returning an error if it has less than `M` elements.
+        #[inline]
+        fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+            check_len(Vec::decode(decoder)?)
+        }
+    }
+
+    impl<'de, Context, T: BorrowDecode<'de, Context>, const M: usize> BorrowDecode<'de, Context>
+        for VecMin<T, M>
+    {
+        /// Decodes the vector like a `Vec`, returning an error if it has less than `M` elements.
+        #[inline]
+        fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+            decoder: &mut D,
+        ) -> Result<Self, DecodeError> {
+            check_len(Vec::borrow_decode(decoder)?)
+        }
+    }
+
+    fn check_len<T, const M: usize>(vec: Vec<T>) -> Result<VecMin<T, M>, DecodeError> {
+        let len = vec.len();
+        VecMin::try_from_vec(vec).map_err(|_| {
+            DecodeError::OtherString(format!("length {len} is less than the minimum {M}"))
+        })
+    }
+}
+
+#[cfg(feature = "borsh")]
+mod __borsh {
+    use alloc::format;
+
+    use borsh::io::{Error, ErrorKind, Read, Result, Write};
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    use super::*;
+
+    impl<T: BorshSerialize, const M: usize> BorshSerialize for VecMin<T, M> {
+        /// Serializes the vector like a `Vec`.
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            self.inner.serialize(writer)
+        }
+    }
+
+    impl<T: BorshDeserialize, const M: usize> BorshDeserialize for VecMin<T, M> {
+        /// Deserializes the vector like a `Vec`, returning an [`ErrorKind::InvalidData`] error if it has less than `M`
+        /// elements.
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            let vec = Vec::deserialize_reader(reader)?;
+            let len = vec.len();
+            Self::try_from_vec(vec).map_err(|_| {
+                Error::new(
+                    ErrorKind::InvalidData,
+                    format!("length {len} is less than the minimum {M}"),
+                )
+            })
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+mod __io {
+    use alloc::format;
+    use std::io::{self, Cursor, ErrorKind, Read, Write};
+
+    use super::*;
+
+    impl<const M: usize> VecMin<u8, M> {
+        /// Writes the bytes to `writer`, prefixed by their length as a little-endian `u64`.
+        pub fn write_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+            writer.write_all(&(self.inner.len() as u64).to_le_bytes())?;
+            writer.write_all(&self.inner)
+        }
+
+        /// Reads bytes written by [`VecMin::write_to`] from `reader`, returning an error of kind [`ErrorKind::InvalidData`]
+        /// if the length prefix is less than `M`.
+        pub fn read_from<R: Read>(reader: R) -> io::Result<Self> {
+            let vec = read_prefixed(reader, M)?;
+
+            // Invariant: `read_prefixed` checked that the length was at least `M`.
+            Ok(Self::from_inner(vec))
+        }
+
+        /// Returns a reader over the bytes, which can also seek.
+        #[inline]
+        pub fn reader(&self) -> Cursor<&[u8]> {
+            Cursor::new(self.inner.as_slice())
+        }
+
+        /// Returns a reader over the bytes, consuming the vector. The vector can be recovered with
+        /// [`Cursor::into_inner`].
+        #[inline]
+        pub fn into_reader(self) -> Cursor<Self> {
+            Cursor::new(self)
+        }
+    }
+
+    // Kept independent of `M` so it is only instantiated once per reader type.
+    fn read_prefixed<R: Read>(mut reader: R, min: usize) -> io::Result<Vec<u8>> {
+        let mut prefix = [0; 8];
+        reader.read_exact(&mut prefix)?;
+
+        let len = u64::from_le_bytes(prefix);
+        if len < min as u64 {
+            return Err(io::Error::new(
+                ErrorKind::InvalidData,
+                format!("length prefix {len} is less than the minimum {min}"),
+            ));
+        }
+
+        // Read through `take` rather than preallocating `len` so a corrupt prefix cannot request a huge allocation.
+        let mut vec = Vec::with_capacity(min);
+        reader.take(len).read_to_end(&mut vec)?;
+        if (vec.len() as u64) < len {
+            return Err(ErrorKind::UnexpectedEof.into());
+        }
+
+        Ok(vec)
+    }
+}
+
+#[cfg(feature = "std")]
+mod __hash {
+    use core::hash::Hash;
+    use core::num::NonZeroUsize;
+    use std::collections::HashMap;
+    use std::collections::hash_map::Entry;
+
+    use super::*;
+
+    impl<T, const M: usize> VecMin<T, M> {
+        /// Groups the elements by the key returned by `key` into a `HashMap`, keeping the order of the elements within
+        /// each group. Every group is non-empty by construction.
+        pub fn group_by_key<K, F>(self, mut key: F) -> HashMap<K, VecOne<T>>
+        where
+            K: Eq + Hash,
+            F: FnMut(&T) -> K,
+        {
+            let mut groups = HashMap::<K, VecOne<T>>::new();
+            for item in self.inner {
+                match groups.entry(key(&item)) {
+                    Entry::Occupied(mut group) => group.get_mut().push(item),
+                    Entry::Vacant(group) => {
+                        group.insert(VecOne::singleton(item));
+                    }
+                }
+            }
+            groups
+        }
+
+        /// Counts the occurrences of each distinct element. Every count is non-zero by construction.
+        pub fn counts(&self) -> HashMap<&T, NonZeroUsize>
+        where
+            T: Eq + Hash,
+        {
+            let mut counts = HashMap::<&T, NonZeroUsize>::new();
+            for item in &self.inner {
+                match counts.entry(item) {
+                    Entry::Occupied(mut count) => *count.get_mut() = count.get().saturating_add(1),
+                    Entry::Vacant(count) => {
+                        count.insert(NonZeroUsize::MIN);
+                    }
+                }
+            }
+            counts
+        }
+
+        /// Returns the most common element and its number of occurrences, preferring the element that occurs first
+        /// on ties.
+        ///
+        /// Fails to compile if `M` is 0.
+        pub fn most_common(&self) -> (&T, NonZeroUsize)
+        where
+            T: Eq + Hash,
+        {
+            const {
+                assert!(
+                    M >= 1,
+                    "`most_common` requires a minimum length of at least 1"
+                )
+            };
+
+            let counts = self.counts();
+            let mut best = (&self.min_slice()[0], NonZeroUsize::MIN);
+            for item in &self.inner {
+                let count = counts[item];
+                if count > best.1 {
+                    best = (item, count);
+                }
+            }
+            best
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+mod __env {
+    use alloc::string::String;
+    use std::env;
+    use std::ffi::OsString;
+
+    use super::*;
+
+    impl VecOne<String> {
+        /// Collects the arguments of the process from [`env::args`], the first of which is conventionally the program
+        /// name.
+        ///
+        /// # Panics
+        /// Panics if any argument is not valid unicode, or if the process was started without any arguments (e.g. by
+        /// calling `execve` with an empty `argv`), which no mainstream platform does on its own.
+        #[track_caller]
+        pub fn from_args() -> Self {
+            Self::try_from_vec(env::args().collect())
+                .unwrap_or_else(|_| panic!("process was started without a program name argument"))
+        }
+    }
+
+    impl VecOne<OsString> {
+        /// Collects the arguments of the process from [`env::args_os`], the first of which is conventionally the
+        /// program name.
+        ///
+        /// # Panics
+        /// Panics if the process was started without any arguments (e.g. by calling `execve` with an empty `argv`),
+        /// which no mainstream platform does on its own.
+        #[track_caller]
+        pub fn from_args_os() -> Self {
+            Self::try_from_vec(env::args_os().collect())
+                .unwrap_or_else(|_| panic!("process was started without a program name argument"))
+        }
+    }
+}
+
+#[cfg(feature = "nonempty")]
+mod __nonempty {
+    use nonempty::NonEmpty;
+
+    use super::*;
+
+    impl<T, const M: usize> VecMin<T, M> {
+        /// Converts into a [`NonEmpty`] without a runtime check.
+        ///
+        /// Fails to compile if `M` is 0, use `NonEmpty::try_from` instead.
+        #[inline]
+        pub fn into_nonempty(self) -> NonEmpty<T> {
+            const {
+                assert!(
+                    M >= 1,
+                    "`into_nonempty` requires a minimum length of at least 1"
+                )
+            };
+
+            let mut tail = self.inner;
+            let head = tail.remove(0);
+            NonEmpty { head, tail }
+        }
+    }
+
+    impl<T> From<NonEmpty<T>> for VecMin<T, 1> {
+        #[inline]
+        fn from(nonempty: NonEmpty<T>) -> Self {
+            let NonEmpty { head, mut tail } = nonempty;
+            tail.insert(0, head);
+
+            // Invariant: A `NonEmpty` is guaranteed to have a length of at least 1.
+            Self::from_inner(tail)
+        }
+    }
+
+    impl<T, const M: usize> TryFrom<VecMin<T, M>> for NonEmpty<T> {
+        type Error = VecMin<T, M>;
+
+        #[inline]
+        fn try_from(vec_min: VecMin<T, M>) -> Result<Self, Self::Error> {
+            if vec_min.inner.is_empty() {
+                return Err(vec_min);
+            }
+
+            let mut tail = vec_min.inner;
+            let head = tail.remove(0);
+            Ok(NonEmpty { head, tail })
+        }
+    }
+}
+
+#[cfg(feature = "numeric")]
+mod __numeric {
+    use core::ops::Add;
+
+    use super::*;
+
+    impl<T: Clone + Add<Output = T>, const M: usize> VecMin<T, M> {
+        /// Replaces every element with the sum of itself and all elements before it, consuming and returning the
+        /// vector.
+        pub fn into_prefix_sums(mut self) -> Self {
+            let mut iter = self.inner.iter_mut();
+            if let Some(first) = iter.next() {
+                let mut total = first.clone();
+                for item in iter {
+                    total = total + item.clone();
+                    *item = total.clone();
+                }
+            }
+            self
+        }
+
+        /// Returns the running totals of the elements, which has the same length as the vector.
+        #[inline]
+        pub fn prefix_sums(&self) -> Self {
+            self.clone().into_prefix_sums()
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+mod __rand {
+    use rand::Rng;
+    use rand::distr::{Distribution, StandardUniform};
+    use rand::seq::{IndexedMutRandom, IndexedRandom, SliceChooseIter, SliceRandom, index};
+
+    use super::*;
+
+    impl<T, const M: usize> VecMin<T, M> {
+        /// Creates a new `VecMin` of `len` random elements, or returns `None` if `len` is less than `M`.
+        pub fn random<R>(len: usize, rng: &mut R) -> Option<Self>
+        where
+            R: Rng + ?Sized,
+            StandardUniform: Distribution<T>,
+        {
+            if len < M {
+                return None;
+            }
+
+            let vec = (0..len).map(|_| rng.random()).collect();
+            // Invariant: We just checked that the length was at least `M`.
+            Some(Self::from_inner(vec))
+        }
+
+        /// See [`SliceRandom::shuffle`].
+        #[inline]
+        pub fn shuffle<R>(&mut self, rng: &mut R)
+        where
+            R: Rng + ?Sized,
+        {
+            self.inner.shuffle(rng);
+        }
+
+        /// Returns an element chosen uniformly at random, which is guaranteed to exist.
+        ///
+        /// Fails to compile if `M` is 0.
+        #[inline]
+        pub fn choose<R>(&self, rng: &mut R) -> &T
+        where
+            R: Rng + ?Sized,
+        {
+            const { assert!(M >= 1, "`choose` requires a minimum length of at least 1") };
+
+            match self.inner.as_slice().choose(rng) {
+                Some(item) => item,
+                None => unreachable!(),
+            }
+        }
+
+        /// Returns a mutable reference to an element chosen uniformly at random, which is guaranteed to exist.
+        ///
+        /// Fails to compile if `M` is 0.
+        #[inline]
+        pub fn choose_mut<R>(&mut self, rng: &mut R) -> &mut T
+        where
+            R: Rng + ?Sized,
+        {
+            const {
+                assert!(
+                    M >= 1,
+                    "`choose_mut` requires a minimum length of at least 1"
+                )
+            };
+
+            match self.inner.as_mut_slice().choose_mut(rng) {
+                Some(item) => item,
+                None => unreachable!(),
+            }
+        }
+
+        /// See [`IndexedRandom::choose_multiple`]. Returns `amount` distinct elements, or every element if there are
+        /// fewer, in random order.
+        #[inline]
+        pub fn choose_multiple<R>(&self, rng: &mut R, amount: usize) -> SliceChooseIter<'_, [T], T>
+        where
+            R: Rng + ?Sized,
+        {
+            self.inner.as_slice().choose_multiple(rng, amount)
+        }
+
+        /// Returns `K` distinct elements chosen uniformly at random, in random order.
+        ///
+        /// Fails to compile if `K` is greater than `M`.
+        pub fn sample<const K: usize, R>(&self, rng: &mut R) -> [&T; K]
+        where
+            R: Rng + ?Sized,
+        {
+            const {
+                assert!(
+                    K <= M,
+                    "`sample` requires `K` to be less than or equal to the minimum length"
+                )
+            };
+
+            let indices = index::sample(rng, self.inner.len(), K);
+            core::array::from_fn(|i| &self.inner[indices.index(i)])
+        }
+
+        /// Returns clones of `K` distinct elements chosen uniformly at random, in random order.
+        ///
+        /// Fails to compile if `K` is greater than `M`.
+        #[inline]
+        pub fn sample_cloned<const K: usize, R>(&self, rng: &mut R) -> [T; K]
+        where
+            T: Clone,
+            R: Rng + ?Sized,
+        {
+            self.sample::<K, R>(rng).map(T::clone)
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+mod __rayon {
+    use rayon::iter::{IntoParallelIterator, ParallelExtend, ParallelIterator};
+    use rayon::slice;
+
+    use super::*;
+
+    impl<T: Send, const M: usize> VecMin<T, M> {
+        /// Creates a new `VecMin` from a parallel iterator, returning an error if the length of the collected `Vec` is
+        /// less than `M`.
+        #[inline]
+        pub fn par_collect(
+            iter: impl IntoParallelIterator<Item = T>,
+        ) -> Result<Self, ConstructError<T, M>> {
+            Self::try_from_vec(iter.into_par_iter().collect())
+        }
+    }
+
+    impl<T: Send, const M: usize> IntoParallelIterator for VecMin<T, M> {
+        type Item = T;
+        type Iter = rayon::vec::IntoIter<T>;
+
+        #[inline]
+        fn into_par_iter(self) -> Self::Iter {
+            self.inner.into_par_iter()
+        }
+    }
+
+    impl<'a, T: Sync, const M: usize> IntoParallelIterator for &'a VecMin<T, M> {
+        type Item = &'a T;
+        type Iter = slice::Iter<'a, T>;
+
+        #[inline]
+        fn into_par_iter(self) -> Self::Iter {
+            self.inner.as_slice().into_par_iter()
+        }
+    }
+
+    impl<'a, T: Send, const M: usize> IntoParallelIterator for &'a mut VecMin<T, M> {
+        type Item = &'a mut T;
+        type Iter = slice::IterMut<'a, T>;
+
+        #[inline]
+        fn into_par_iter(self) -> Self::Iter {
+            self.inner.as_mut_slice().into_par_iter()
+        }
+    }
+
+    impl<T: Send, const M: usize> ParallelExtend<T> for VecMin<T, M> {
+        #[inline]
+        fn par_extend<I>(&mut self, iter: I)
+        where
+            I: IntoParallelIterator<Item = T>,
+        {
+            self.inner.par_extend(iter);
+        }
+    }
+
+    impl<'a, T: Copy + Send + Sync + 'a, const M: usize> ParallelExtend<&'a T> for VecMin<T, M> {
+        #[inline]
+        fn par_extend<I>(&mut self, iter: I)
+        where
+            I: IntoParallelIterator<Item = &'a T>,
+        {
+            self.inner.par_extend(iter);
+        }
+    }
+}
+
+#[cfg(feature = "schemars")]
+mod __schemars {
+    use alloc::borrow::Cow;
+    use alloc::format;
+
+    use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+
+    use super::*;
+
+    impl<T: JsonSchema, const M: usize> JsonSchema for VecMin<T, M> {
+        #[inline]
+        fn inline_schema() -> bool {
+            true
+        }
+
+        fn schema_name() -> Cow<'static, str> {
+            format!("Array_of_at_least_{M}_{}", T::schema_name()).into()
+        }
+
+        fn schema_id() -> Cow<'static, str> {
+            format!("vecmin::VecMin<{}, {M}>", T::schema_id()).into()
+        }
+
+        /// The schema of `Vec<T>` with `minItems` set to `M`.
+        fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+            json_schema!({
+                "type": "array",
+                "items": generator.subschema_for::<T>(),
+                "minItems": M,
+            })
+        }
+    }
+}
+
+#[cfg(feature = "sqlx")]
+mod __sqlx {
+    use alloc::format;
+
+    use sqlx_core::database::Database;
+    use sqlx_core::decode::Decode;
+    use sqlx_core::encode::{Encode, IsNull};
+    use sqlx_core::error::BoxDynError;
+    use sqlx_core::types::Type;
+
+    use super::*;
+
+    impl<T, DB: Database, const M: usize> Type<DB> for VecMin<T, M>
+    where
+        Vec<T>: Type<DB>,
+    {
+        #[inline]
+        fn type_info() -> DB::TypeInfo {
+            <Vec<T> as Type<DB>>::type_info()
+        }
+
+        #[inline]
+        fn compatible(ty: &DB::TypeInfo) -> bool {
+            <Vec<T> as Type<DB>>::compatible(ty)
+        }
+    }
+
+    impl<'q, T, DB: Database, const M: usize> Encode<'q, DB> for VecMin<T, M>
+    where
+        Vec<T>: Encode<'q, DB>,
+    {
+        #[inline]
+        fn encode(self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+            self.inner.encode(buf)
+        }
+
+        #[inline]
+        fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+            self.inner.encode_by_ref(buf)
+        }
+
+        #[inline]
+        fn produces(&self) -> Option<DB::TypeInfo> {
+            self.inner.produces()
+        }
+
+        #[inline]
+        fn size_hint(&self) -> usize {
+            self.inner.size_hint()
+        }
+    }
+
+    impl<'r, T, DB: Database, const M: usize> Decode<'r, DB> for VecMin<T, M>
+    where
+        Vec<T>: Decode<'r, DB>,
+    {
+        #[inline]
+        fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+            let vec = Vec::<T>::decode(value)?;
+            let len = vec.len();
+            Self::try_from_vec(vec).map_err(|_| length_error(len, M))
+        }
+    }
+
+    #[cold]
+    fn length_error(len: usize, min: usize) -> BoxDynError {
+        format!("length {len} is less than the minimum {min}").into()
+    }
+}
+
+#[cfg(feature = "subtle")]
+mod __subtle {
+    use subtle::{Choice, ConstantTimeEq};
+
+    use super::*;
+
+    impl<T: ConstantTimeEq, const M: usize> ConstantTimeEq for VecMin<T, M> {
+        /// Compares the elements in constant time. The lengths are compared in variable time, and vectors of different
+        /// lengths are unequal.
+        #[inline]
+        fn ct_eq(&self, other: &Self) -> Choice {
+            self.as_slice().ct_eq(other.as_slice())
+        }
+    }
+
+    impl<T: ConstantTimeEq, const M: usize> VecMin<T, M> {
+        /// Compares only the first `M` elements in constant time, ignoring the elements after them.
+        #[inline]
+        pub fn ct_eq_min(&self, other: &Self) -> Choice {
+            self.min_slice()
+                .as_slice()
+                .ct_eq(other.min_slice().as_slice())
+        }
+    }
+}
+
+#[cfg(feature = "utoipa")]
+mod __utoipa {
+    use alloc::boxed::Box;
+    use alloc::string::String;
+
+    use utoipa::openapi::RefOr;
+    use utoipa::openapi::schema::{ArrayBuilder, ArrayItems, Schema};
+    use utoipa::{PartialSchema, ToSchema};
+
+    use super::*;
+
+    impl<T: ToSchema, const M: usize> PartialSchema for VecMin<T, M> {
+        /// The schema of `Vec<T>` with `minItems` set to `M`.
+        fn schema() -> RefOr<Schema> {
+            ArrayBuilder::new()
+                .items(ArrayItems::RefOrSchema(Box::new(T::schema())))
+                .min_items(Some(M))
+                .into()
+        }
+    }
+
+    impl<T: ToSchema, const M: usize> ToSchema for VecMin<T, M> {
+        fn schemas(schemas: &mut Vec<(String, RefOr<Schema>)>) {
+            T::schemas(schemas);
+        }
+    }
+}
+
+#[cfg(feature = "vec1")]
+mod __vec1 {
+    use vec1::Vec1;
+
+    use super::*;
+
+    impl<T, const M: usize> VecMin<T, M> {
+        /// Converts into a [`Vec1`] without a runtime check.
+        ///
+        /// Fails to compile if `M` is 0, use `Vec1::try_from` instead.
+        #[inline]
+        pub fn into_vec1(self) -> Vec1<T> {
+            const {
+                assert!(
+                    M >= 1,
+                    "`into_vec1` requires a minimum length of at least 1"
+                )
+            };
+
+            match Vec1::try_from_vec(self.inner) {
+                Ok(vec1) => vec1,
+                Err(_) => unreachable!(),
+            }
+        }
+    }
+
+    impl<T> From<Vec1<T>> for VecMin<T, 1> {
+        #[inline]
+        fn from(vec1: Vec1<T>) -> Self {
+            // Invariant: A `Vec1` is guaranteed to have a length of at least 1.
+            Self::from_inner(vec1.into_vec())
+        }
+    }
+
+    impl<T, const M: usize> TryFrom<VecMin<T, M>> for Vec1<T> {
+        type Error = VecMin<T, M>;
+
+        #[inline]
+        fn try_from(vec_min: VecMin<T, M>) -> Result<Self, Self::Error> {
+            if vec_min.inner.is_empty() {
+                return Err(vec_min);
+            }
+
+            match Vec1::try_from_vec(vec_min.inner) {
+                Ok(vec1) => Ok(vec1),
+                Err(_) => unreachable!(),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "wasm-bindgen")]
+mod __wasm_bindgen {
+    use js_sys::{
+        Array, Float32Array, Float64Array, Int8Array, Int16Array, Int32Array, Uint8Array,
+        Uint16Array, Uint32Array,
+    };
+    use wasm_bindgen::JsValue;
+
+    use super::*;
+
+    impl<T: Into<JsValue>, const M: usize> From<VecMin<T, M>> for Array {
+        #[inline]
+        fn from(vec_min: VecMin<T, M>) -> Self {
+            vec_min.inner.into_iter().map(Into::into).collect::<Array>()
+        }
+    }
+
+    impl<const M: usize> TryFrom<Array> for VecMin<JsValue, M> {
+        type Error = ConstructError<JsValue, M>;
+
+        /// Copies the elements of the array, returning an error if there are less than `M`. The error converts into a
+        /// JavaScript `Error` through [`JsError`](wasm_bindgen::JsError), so `?` can be used in functions exported with `#[wasm_bindgen]`.
+        #[inline]
+        fn try_from(array: Array) -> Result<Self, Self::Error> {
+            Self::try_from_vec(array.to_vec())
+        }
+    }
+
+    macro_rules! impl_typed_array {
+        ($($ty:ty => $array:ty;)*) => {$(
+            impl<const M: usize> From<VecMin<$ty, M>> for $array {
+                #[inline]
+                fn from(vec_min: VecMin<$ty, M>) -> Self {
+                    <$array>::from(vec_min.inner.as_slice())
+                }
+            }
+
+            impl<const M: usize> TryFrom<$array> for VecMin<$ty, M> {
+                type Error = ConstructError<$ty, M>;
+
+                #[inline]
+                fn try_from(array: $array) -> Result<Self, Self::Error> {
+                    Self::try_from_vec(array.to_vec())
+                }
+            }
+        )*};
+    }
+
+    impl_typed_array! {
+        u8 => Uint8Array;
+        i8 => Int8Array;
+        u16 => Uint16Array;
+        i16 => Int16Array;
+        u32 => Uint32Array;
+        i32 => Int32Array;
+        f32 => Float32Array;
+        f64 => Float64Array;
+    }
+}
+
+#[cfg(feature = "zeroize")]
+mod __zeroize {
+    use zeroize::Zeroize;
+
+    use super::*;
+
+    /// Zeroizes every element in place along with the spare capacity of the vector.
+    ///
+    /// Unlike `Vec<T>`, the vector is not cleared as that would break the minimum length, so the length is unchanged.
+    /// `VecMin` does not zeroize on drop, wrap it in [`ZeroizingVecMin`](crate::ZeroizingVecMin) for that. Elements
+    /// removed from the vector are not zeroized once they have left it.
+    impl<T: Zeroize, const M: usize> Zeroize for VecMin<T, M> {
+        fn zeroize(&mut self) {
+            self.inner.iter_mut().zeroize();
+            self.inner.spare_capacity_mut().zeroize();
+        }
     }
 }
 
 #[cfg(feature = "serde")]
 mod __serde {
-    use serde::de::Error;
+    use serde::de::{Error, Expected};
     use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
     use super::*;
@@ -727,18 +2789,762 @@ mod __serde {
         where
             S: Serializer,
         {
-            self.vec.serialize(serializer)
+            self.inner.serialize(serializer)
         }
     }
 
-    impl<'de, T: Deserialize<'de> + Debug, const M: usize> Deserialize<'de> for VecMin<T, M> {
+    impl<'de, T: Deserialize<'de>, const M: usize> Deserialize<'de> for VecMin<T, M> {
+        /// Returns an `invalid_length` error naming the actual length and `M` if the sequence is too short.
         #[inline]
         fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where
             D: Deserializer<'de>,
         {
             let vec = Vec::deserialize(deserializer)?;
-            Self::try_from_vec(vec).map_err(Error::custom)
+            let len = vec.len();
+            Self::try_from_vec(vec).map_err(|_| Error::invalid_length(len, &MinLength::<M>))
+        }
+    }
+
+    /// The `Expected` of a sequence that is shorter than `M`.
+    struct MinLength<const M: usize>;
+
+    impl<const M: usize> Expected for MinLength<M> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            write!(f, "a sequence of at least {M} elements")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{vecmin, vecone};
+
+    #[test]
+    fn runs() {
+        let v = vecone![1, 1, 2, 3, 3, 3];
+
+        assert_eq!(v.runs().map(<[_]>::len).collect::<Vec<_>>(), [2, 1, 3]);
+        assert_eq!(v.runs_by(|a, b| a <= b).count(), 1);
+        assert_eq!(v.first_run(), &[1, 1]);
+    }
+
+    #[test]
+    fn min_slices() {
+        let mut v = vecmin![2; [1, 2, 3]];
+        assert_eq!(v.split_at_min(), (&[1, 2], &[3][..]));
+        assert_eq!(v.last_min_slice(), &[2, 3]);
+
+        v.last_min_slice_mut()[1] = 4;
+        v.min_slice_mut()[0] = 0;
+        assert_eq!(*v, [0, 2, 4]);
+    }
+
+    #[test]
+    fn new_padded() {
+        assert_eq!(*VecMin::<_, 3>::new_padded([1].to_vec(), 0), [1, 0, 0]);
+        assert_eq!(*VecMin::<_, 1>::new_padded([1, 2].to_vec(), 0), [1, 2]);
+
+        let mut next = 0;
+        let v = VecMin::<_, 2>::new_padded_with(Vec::new(), || {
+            next += 1;
+            next
+        });
+        assert_eq!(*v, [1, 2]);
+    }
+
+    #[cfg(not(vecmin_no_unsafe))]
+    #[test]
+    fn ensure_min_with() {
+        let mut v = vecmin![2; [1, 2, 3]];
+        unsafe { v.as_mut_vec() }.clear();
+        v.ensure_min_with(|| 7);
+        assert_eq!(*v, [7, 7]);
+
+        v.push(8);
+        v.ensure_min_with(|| unreachable!());
+        assert_eq!(*v, [7, 7, 8]);
+    }
+
+    #[test]
+    fn small_arity() {
+        assert_eq!(*VecOne::singleton('a'), ['a']);
+        assert_eq!(*VecMin::pair(1, 2), [1, 2]);
+        assert_eq!(VecMin::triple(1, 2, 3).minimum(), 3);
+    }
+
+    #[test]
+    fn mem_stats() {
+        let mut v = VecMin::<u32, 1>::from_array([1]);
+        v.reserve_exact(3);
+
+        let stats = v.mem_stats();
+        assert_eq!((stats.len, stats.spare_capacity), (1, stats.capacity - 1));
+        assert_eq!(stats.bytes, stats.capacity * 4);
+        assert_eq!(
+            [stats, stats].into_iter().sum::<MemStats>().bytes,
+            stats.bytes * 2
+        );
+        let full = MemStats {
+            len: usize::MAX,
+            ..stats
+        };
+        assert_eq!([full, stats].into_iter().sum::<MemStats>().len, usize::MAX);
+        assert_eq!(VecMin::<(), 1>::from_array([()]).allocated_bytes(), 0);
+    }
+
+    #[test]
+    fn group_by_key() {
+        let groups = vecone![1, 2, 3, 4, 5].group_by_key_btree(|x| x % 2);
+        assert_eq!(*groups[&0], [2, 4]);
+        assert_eq!(*groups[&1], [1, 3, 5]);
+
+        #[cfg(feature = "std")]
+        assert_eq!(
+            *vecone!["a", "bb", "c"].group_by_key(|s| s.len())[&1],
+            ["a", "c"]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn most_common() {
+        use core::num::NonZeroUsize;
+
+        let v = vecone![3, 1, 3, 1, 2];
+        assert_eq!(v.counts()[&2], NonZeroUsize::MIN);
+        assert_eq!(v.most_common(), (&3, NonZeroUsize::new(2).unwrap()));
+    }
+
+    #[test]
+    fn reversed() {
+        let v = vecmin![2; [1, 2, 3]];
+        assert_eq!(*v.to_reversed(), [3, 2, 1]);
+        assert_eq!(*v.into_reversed(), [3, 2, 1]);
+    }
+
+    #[test]
+    fn sorted() {
+        let v = vecmin![2; [3, 1, 2]];
+        assert_eq!(*v.to_sorted(), [1, 2, 3]);
+        assert_eq!(*v.clone().into_sorted_by(|a, b| b.cmp(a)), [3, 2, 1]);
+        assert_eq!(*v.into_sorted_by_key(|x| x % 3), [3, 1, 2]);
+    }
+
+    #[test]
+    fn surplus_len() {
+        let v = vecmin![2; [1, 2, 3]];
+        assert_eq!(v.surplus_len(), 1);
+        assert!(v.can_remove(1) && !v.can_remove(2) && !v.is_minimum());
+    }
+
+    #[test]
+    fn cells() {
+        let mut v = vecmin![2; [1, 2, 3]];
+        let [a, b] = v.min_cells();
+        a.swap(b);
+        v.as_slice_of_cells()[2].set(4);
+        assert_eq!(*v, [2, 1, 4]);
+    }
+
+    #[test]
+    fn try_from_str() {
+        assert_eq!(*VecMin::<char, 2>::try_from("hé").unwrap(), ['h', 'é']);
+        VecMin::<char, 3>::try_from("hé").unwrap_err();
+        assert_eq!(VecMin::<u8, 3>::try_from("hé").unwrap().len(), 3);
+    }
+
+    #[test]
+    fn read_header() {
+        let packet = vecmin![6; [0xCA, 0xFE, 1, 0, 0, 0, 0xFF]];
+        assert_eq!(packet.read_u16_be::<0>(), 0xCAFE);
+        assert_eq!(packet.read_u32_le::<2>(), 1);
+        assert_eq!(packet.read_bytes::<5, 1>(), &[0]);
+    }
+
+    #[test]
+    fn collect_exact() {
+        assert_eq!(*VecMin::<_, 2>::collect_exact(0..3, 3).unwrap(), [0, 1, 2]);
+        assert_eq!(
+            VecMin::<_, 2>::collect_exact(0..3, 1),
+            Err(CollectExactError::BelowMinimum { expected: 1 })
+        );
+        assert!(matches!(
+            VecMin::<_, 2>::collect_exact(0..2, 3),
+            Err(CollectExactError::TooFew { items, .. }) if items == [0, 1]
+        ));
+
+        let mut iter = 0..5;
+        assert!(matches!(
+            VecMin::<_, 2>::collect_exact(&mut iter, 3),
+            Err(CollectExactError::TooMany { items, .. }) if items == [0, 1, 2, 3]
+        ));
+        assert_eq!(iter.next(), Some(4));
+    }
+
+    #[test]
+    fn drain_min() {
+        let mut v = vecmin![2; [1, 2, 3, 4, 5]];
+        assert_eq!(v.drain_min::<3, _>(..2), Err(DrainMinError::TooShort(2)));
+        assert_eq!(
+            v.drain_min::<1, _>(..4),
+            Err(DrainMinError::Modify(ModifyError))
+        );
+
+        let batch: VecMin<_, 2> = v.drain_min(1..3).unwrap();
+        assert_eq!(*batch, [2, 3]);
+        assert_eq!(*v, [1, 4, 5]);
+    }
+
+    #[test]
+    fn overwrite() {
+        let mut v = vecmin![2; [1, 2, 3]];
+        v.copy_min_from(&[4, 5]);
+        assert_eq!(*v, [4, 5, 3]);
+
+        let mut other = [0; 3];
+        v.swap_with_slice(&mut other);
+        v.copy_from_slice(&[other[2], other[1], other[0]]);
+        assert_eq!(*v, [3, 5, 4]);
+    }
+
+    #[test]
+    fn shrink_to_min() {
+        let mut v = vecmin![1; [1, 2, 3]];
+        v.reserve(100);
+        v.shrink_to_min_with_headroom(4);
+        assert_eq!((v.as_slice(), v.capacity()), (&[1][..], 5));
+
+        v.shrink_to_min();
+        assert_eq!(v.capacity(), 1);
+    }
+
+    #[test]
+    fn into_frames() {
+        let v = vecmin![4; [1, 2, 3, 4, 5]];
+        assert_eq!(
+            v.into_frames::<2>(),
+            ([[1, 2], [3, 4]].to_vec(), [5].to_vec())
+        );
+    }
+
+    #[test]
+    fn unique() {
+        let mut v = vecmin![2; [3, 1, 3, 2, 1]];
+        v.unique().unwrap();
+        assert_eq!(*v, [3, 1, 2]);
+
+        let mut v = vecmin![3; [1, 2, 1, 1]];
+        v.unique_by_key(|x| x % 2).unwrap_err();
+        v.unique_to_min();
+        assert_eq!(*v, [1, 2, 1]);
+    }
+
+    #[test]
+    fn into_min_parts() {
+        let v = vecmin![2; [1, 2, 3]];
+        assert_eq!(v.into_min_parts(), ([1, 2], [3].to_vec()));
+    }
+
+    #[test]
+    fn from_head_tail() {
+        let v = VecMin::from_head_tail([1, 2], [3].to_vec());
+        assert_eq!(*v, [1, 2, 3]);
+        assert_eq!(
+            VecMin::from_head_tail(v.into_min_parts().0, Vec::new()),
+            vecmin![2; [1, 2]]
+        );
+    }
+
+    #[test]
+    fn from_larger_array() {
+        let v = VecMin::<_, 2>::from_larger_array([1, 2, 3]);
+        assert_eq!((v.as_slice(), v.minimum()), (&[1, 2, 3][..], 2));
+    }
+
+    #[test]
+    fn eq_slices_and_arrays() {
+        let v = vecmin![2; [1, 2, 3]];
+        let slice: &[i32] = &[1, 2, 3];
+        assert_eq!(v, [1, 2, 3]);
+        assert_eq!([1, 2, 3], v);
+        assert_eq!(v, slice);
+        assert_eq!(slice, v);
+        assert_ne!(v, [1, 2][..]);
+    }
+
+    #[test]
+    fn retain() {
+        let mut v = vecmin![2; [1, 2, 3, 4]];
+        assert_eq!(v.retain(|x| *x > 3), Err(ModifyError));
+        assert_eq!(*v, [1, 2, 3, 4]);
+
+        v.retain(|x| x % 2 == 0).unwrap();
+        assert_eq!(*v, [2, 4]);
+    }
+
+    #[test]
+    fn retain_to_min() {
+        let mut v = vecmin![2; [1, 2, 3, 4, 5]];
+        let mut calls = 0;
+        v.retain_to_min(|x| {
+            calls += 1;
+            *x == 2
+        });
+        assert_eq!((v.as_slice(), calls), (&[2, 5][..], 4));
+    }
+
+    #[test]
+    fn swap_retain() {
+        let mut v = vecmin![2; [1, 2, 3, 4, 5]];
+        assert_eq!(v.swap_retain(|x| *x == 1), Err(ModifyError));
+        v.swap_retain(|x| x % 2 == 1).unwrap();
+        assert_eq!(*v, [1, 5, 3]);
+
+        v.swap_retain_to_min(|_| false);
+        assert_eq!(*v, [3, 5]);
+    }
+
+    #[test]
+    fn dedup() {
+        let mut v = vecmin![3; [1, 1, 2, 2, 3]];
+        assert_eq!(v.dedup(), Ok(()));
+        assert_eq!(*v, [1, 2, 3]);
+
+        let mut v = vecmin![3; [1, 1, 2, 2, 2]];
+        assert_eq!(v.dedup_by_key(|x| *x / 2), Err(ModifyError));
+        v.dedup_to_min();
+        assert_eq!(*v, [1, 2, 2]);
+    }
+
+    #[test]
+    fn extract_if_min() {
+        let mut v = vecmin![2; [1, 2, 3, 4, 5, 6]];
+        let odd: Vec<_> = v.extract_if_min(.., |x| *x % 2 == 1).collect();
+        assert_eq!((odd, v.as_slice()), ([1, 3, 5].to_vec(), &[2, 4, 6][..]));
+
+        assert_eq!(v.extract_if_min(1.., |_| true).next(), Some(4));
+        assert_eq!(*v, [2, 6]);
+        assert_eq!(v.extract_if_min(.., |_| true).next(), None);
+
+        let mut v = vecmin![3; [1, 2, 3, 4, 5, 6, 7, 8]];
+        let mut iter = v.extract_if_min(..7, |x| *x != 3 && *x < 7);
+        assert_eq!(iter.by_ref().take(2).collect::<Vec<_>>(), [1, 2]);
+        drop(iter);
+        assert_eq!(*v, [3, 4, 5, 6, 7, 8]);
+
+        let removed: Vec<_> = v.extract_if_min(.., |x| *x % 2 == 0).collect();
+        assert_eq!(
+            (removed, v.as_slice()),
+            ([4, 6, 8].to_vec(), &[3, 5, 7][..])
+        );
+    }
+
+    #[test]
+    fn extract_if_min_leak() {
+        let mut v = vecmin![2; [1, 2, 3, 4, 5]];
+        let mut iter = v.extract_if_min(.., |_| true);
+        assert_eq!(iter.next(), Some(1));
+        core::mem::forget(iter);
+        v.assert_invariant();
+
+        let mut v = vecmin![2; [1, 2, 3, 4, 5]];
+        let mut iter = v.extract_if_min(.., |x| *x != 4);
+        assert_eq!(iter.nth(1), Some(2));
+        core::mem::forget(iter);
+        v.assert_invariant();
+        assert_eq!(v[..2], [3, 4]);
+    }
+
+    #[test]
+    fn split_off_min() {
+        let mut v = vecmin![2; [1, 2, 3, 4]];
+        assert_eq!(v.split_off(1), Err(ModifyError));
+        assert_eq!(v.split_off_min(), [3, 4]);
+        assert!(v.is_minimum());
+    }
+
+    #[test]
+    fn iter_cycle() {
+        let v = vecone![1, 2];
+        assert_eq!(
+            v.iter_cycle().take(5).collect::<Vec<_>>(),
+            [&1, &2, &1, &2, &1]
+        );
+    }
+
+    #[test]
+    fn iter_tagged() {
+        use crate::iter::MinElem;
+
+        let mut v = vecmin![2; [1, 2, 3]];
+        assert_eq!(
+            v.iter_tagged().rev().collect::<Vec<_>>(),
+            [
+                MinElem::Extra(&3),
+                MinElem::Required(&2),
+                MinElem::Required(&1)
+            ]
+        );
+
+        v.iter_tagged_mut()
+            .filter(MinElem::is_required)
+            .for_each(|x| *x.into_inner() *= 10);
+        assert_eq!(v, [10, 20, 3].to_vec());
+    }
+
+    #[test]
+    fn frames() {
+        let mut v = vecmin![5; [1, 2, 3, 4, 5, 6, 7]];
+
+        assert_eq!(v.min_frames::<2>(), 2);
+        assert_eq!(
+            v.frames::<2>().collect::<Vec<_>>(),
+            [&[1, 2], &[3, 4], &[5, 6]]
+        );
+        assert_eq!(v.frames_remainder::<2>(), [7]);
+        assert_eq!(v.first_frame::<3>(), &[1, 2, 3]);
+
+        v.frames_mut::<3>().for_each(|frame| frame.reverse());
+        assert_eq!(v, [3, 2, 1, 6, 5, 4, 7].to_vec());
+    }
+
+    #[test]
+    fn drain_leak() {
+        let mut v = vecmin![2; [1, 2, 3, 4, 5]];
+
+        core::mem::forget(v.drain(..3).unwrap());
+        v.assert_invariant();
+
+        let mut v = vecmin![2; [1, 2, 3, 4, 5]];
+        assert_eq!(v.drain(1..4).unwrap().collect::<Vec<_>>(), [2, 3, 4]);
+        assert_eq!(v, [1, 5].to_vec());
+    }
+
+    #[test]
+    fn replace_range() {
+        let mut v = vecmin![3; [1, 2, 3, 4]];
+
+        v.replace_range(..2, &[]).unwrap_err();
+        assert_eq!(v.replace_range(1..3, &[5]), Ok([2, 3].to_vec()));
+        assert_eq!(v, [1, 5, 4].to_vec());
+    }
+
+    #[test]
+    fn extend_from_slices() {
+        let mut v = vecone![1];
+        v.extend_from_slices(&[&[2, 3], &[], &[4]]);
+
+        assert_eq!(v, [1, 2, 3, 4].to_vec());
+        assert!(v.capacity() >= 4);
+    }
+
+    #[test]
+    fn retain_drain() {
+        let mut v = vecmin![2; [1, 2, 3, 4]];
+
+        v.retain_drain(|x| *x == 1).unwrap_err();
+        assert_eq!(v, [1, 2, 3, 4].to_vec());
+
+        assert_eq!(v.retain_drain(|x| x % 2 == 0), Ok([1, 3].to_vec()));
+        assert_eq!(v, [2, 4].to_vec());
+    }
+
+    #[test]
+    fn position() {
+        let v = vecone![3, 1, 4, 1, 5, 9, 2, 9];
+
+        assert_eq!(v.position_min(), 1);
+        assert_eq!(v.position_max(), 7);
+        assert_eq!(v.position_min_by_key(|x: &u32| x.abs_diff(4)), 2);
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn zeroize() {
+        use zeroize::Zeroize;
+
+        let mut v = vecone![1u8, 2, 3];
+        v.zeroize();
+        assert_eq!(v, [0, 0, 0].to_vec());
+
+        let mut v = vecone![1u8, 2, 3];
+        v.truncate(1).unwrap();
+        v.zeroize();
+        assert_eq!((v.as_slice(), v.capacity()), (&[0][..], 3));
+        #[cfg(not(vecmin_no_unsafe))]
+        // Safety: The spare capacity was initialized by the truncated elements and then zeroized.
+        assert!(
+            v.spare_capacity_mut()
+                .iter()
+                .all(|x| unsafe { x.assume_init() } == 0)
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn choose() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut v = VecMin::<u8, 2>::random(4, &mut rng).unwrap();
+        assert!(VecMin::<u8, 2>::random(1, &mut rng).is_none());
+
+        let chosen = *v.choose(&mut rng);
+        assert!(v.contains(&chosen));
+        *v.choose_mut(&mut rng) = 0;
+        assert_eq!(v.choose_multiple(&mut rng, 8).count(), 4);
+
+        let mut sorted = v.clone();
+        sorted.sort_unstable();
+        v.shuffle(&mut rng);
+        v.sort_unstable();
+        assert_eq!(v, sorted);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn sample() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let v = vecmin![3; [1, 2, 3, 4, 5]];
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        for _ in 0..32 {
+            let [a, b, c] = v.sample_cloned(&mut rng);
+            assert!(a != b && b != c && a != c);
+            assert!(v.contains(&a) && v.contains(&b) && v.contains(&c));
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn rayon() {
+        use rayon::prelude::*;
+
+        let mut v = VecMin::<u32, 2>::par_collect((1..=3).into_par_iter()).unwrap();
+        (&mut v).into_par_iter().for_each(|x| *x *= 2);
+        v.par_extend([8].par_iter());
+        assert_eq!((&v).into_par_iter().sum::<u32>(), 20);
+        assert_eq!(v.into_par_iter().max(), Some(8));
+
+        VecMin::<u32, 2>::par_collect([1].into_par_iter()).unwrap_err();
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn json_schema() {
+        let schema = schemars::schema_for!(VecMin<u8, 2>);
+        assert_eq!(schema.get("minItems"), Some(&2.into()));
+        assert_eq!(schema.get("type"), Some(&"array".into()));
+    }
+
+    #[cfg(feature = "utoipa")]
+    #[test]
+    fn to_schema() {
+        use utoipa::PartialSchema;
+        use utoipa::openapi::RefOr;
+        use utoipa::openapi::schema::Schema;
+
+        let RefOr::T(Schema::Array(array)) = VecMin::<u8, 2>::schema() else {
+            panic!("expected an array schema");
+        };
+        assert_eq!(array.min_items, Some(2));
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let v = VecMin::<u8, 3>::arbitrary(&mut Unstructured::new(&[])).unwrap();
+        assert_eq!(*v, [0, 0, 0]);
+
+        let v = VecMin::<u8, 2>::arbitrary_take_rest(Unstructured::new(&[1, 2, 3, 4])).unwrap();
+        assert_eq!(v.min_slice(), &[1, 2]);
+        assert_eq!(VecMin::<u8, 2>::size_hint(0).0, 2);
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn bincode() {
+        let config = bincode::config::standard();
+        let bytes = bincode::encode_to_vec(vecmin![2; [1u8, 2, 3]], config).unwrap();
+        assert_eq!(bytes, [3, 1, 2, 3]);
+
+        let (v, _): (VecMin<u8, 3>, _) = bincode::decode_from_slice(&bytes, config).unwrap();
+        assert_eq!(v, [1, 2, 3]);
+        let (v, _): (VecMin<&str, 1>, _) =
+            bincode::borrow_decode_from_slice(&[1, 1, b'a'], config).unwrap();
+        assert_eq!(v, ["a"]);
+
+        bincode::decode_from_slice::<VecMin<u8, 4>, _>(&bytes, config).unwrap_err();
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn borsh() {
+        let bytes = borsh::to_vec(&vecmin![2; [1u8, 2, 3]]).unwrap();
+        assert_eq!(bytes, [3, 0, 0, 0, 1, 2, 3]);
+        assert_eq!(
+            borsh::from_slice::<VecMin<u8, 3>>(&bytes).unwrap(),
+            [1, 2, 3]
+        );
+
+        let err = borsh::from_slice::<VecMin<u8, 4>>(&bytes).unwrap_err();
+        assert_eq!(err.kind(), borsh::io::ErrorKind::InvalidData);
+    }
+
+    #[cfg(feature = "arrow")]
+    #[test]
+    fn arrow() {
+        use arrow_array::Int32Array;
+
+        let v = vecmin![2; [1, 2, 3]];
+        let ptr = v.as_ptr();
+
+        let array = Int32Array::from(v);
+        assert_eq!(array.values().as_ptr(), ptr);
+
+        let v = VecMin::<i32, 2>::try_from(array).unwrap();
+        assert_eq!(v.as_ptr(), ptr);
+
+        VecMin::<i32, 2>::try_from(Int32Array::from([Some(1), None, Some(3)].to_vec()))
+            .unwrap_err();
+        let short = VecMin::<i32, 4>::try_from(Int32Array::from(v)).unwrap_err();
+        assert_eq!(short.len(), 3);
+    }
+
+    #[cfg(feature = "subtle")]
+    #[test]
+    fn ct_eq() {
+        use subtle::ConstantTimeEq;
+
+        let a = vecmin![2; [1u8, 2, 3]];
+        let b = vecmin![2; [1u8, 2, 4]];
+
+        assert!(bool::from(a.ct_eq(&a.clone())));
+        assert!(!bool::from(a.ct_eq(&b)));
+        assert!(bool::from(a.ct_eq_min(&b)));
+    }
+
+    #[cfg(feature = "numeric")]
+    #[test]
+    fn prefix_sums() {
+        let v = vecone![1, 2, 3, 4];
+        assert_eq!(*v.prefix_sums(), [1, 3, 6, 10]);
+        assert_eq!(*VecMin::<i32, 0>::default().into_prefix_sums(), [0i32; 0]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn panic_safety() {
+        use core::cell::Cell;
+        use std::panic::{AssertUnwindSafe, catch_unwind};
+
+        struct Bomb<'a>(u8, &'a Cell<u8>);
+
+        impl Drop for Bomb<'_> {
+            fn drop(&mut self) {
+                self.1.set(self.1.get() + 1);
+                if self.0 == 0 {
+                    panic!("bomb");
+                }
+            }
         }
+
+        let mut v = vecmin![2; [1, 2]];
+        catch_unwind(AssertUnwindSafe(|| {
+            v.extend(
+                [3, 4, 5]
+                    .into_iter()
+                    .map(|x| if x < 5 { x } else { panic!("iterator") }),
+            )
+        }))
+        .unwrap_err();
+        assert_eq!(v, [1, 2, 3, 4].to_vec());
+
+        let drops = Cell::new(0);
+        let mut v = VecMin::<_, 2>::from_array([Bomb(1, &drops), Bomb(0, &drops)]);
+        catch_unwind(AssertUnwindSafe(|| {
+            v.edit()
+                .truncate(0)
+                .push(Bomb(2, &drops))
+                .push(Bomb(3, &drops))
+                .apply()
+        }))
+        .unwrap_err();
+
+        v.assert_invariant();
+        assert_eq!(drops.get(), 2);
+        drop(v);
+        assert_eq!(drops.get(), 4);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_args() {
+        let args = VecOne::from_args();
+        assert_eq!(args.first(), std::env::args().next().as_ref());
+        assert_eq!(VecOne::from_args_os().len(), args.len());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn write_read() {
+        let v = vecone![1u8, 2, 3];
+
+        let mut buf = Vec::new();
+        v.write_to(&mut buf).unwrap();
+        assert_eq!(VecOne::read_from(buf.as_slice()).unwrap(), v);
+        assert!(VecMin::<u8, 4>::read_from(buf.as_slice()).is_err());
+        assert!(VecOne::read_from(&buf[..buf.len() - 1]).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reader() {
+        use std::io::Read;
+
+        let v = vecone![1u8, 2, 3];
+        let mut out = [0; 2];
+        v.reader().read_exact(&mut out).unwrap();
+        assert_eq!(out, [1, 2]);
+
+        let mut reader = v.clone().into_reader();
+        reader.read_exact(&mut out).unwrap();
+        assert_eq!(reader.into_inner(), v);
+    }
+
+    #[cfg(feature = "nonempty")]
+    #[test]
+    fn nonempty() {
+        let v = VecOne::from(nonempty::nonempty![1, 2]);
+        assert_eq!(v.clone().into_nonempty(), nonempty::nonempty![1, 2]);
+
+        let mut tail = [2].to_vec();
+        tail.reserve(1);
+        let ptr = tail.as_ptr();
+        let v = VecOne::from(nonempty::NonEmpty { head: 1, tail });
+        assert_eq!((v.as_ptr(), v.as_slice()), (ptr, &[1, 2][..]));
+        assert_eq!(v.into_nonempty().tail.as_ptr(), ptr);
+
+        assert!(nonempty::NonEmpty::try_from(VecMin::<i32, 0>::default()).is_err());
+        assert_eq!(
+            nonempty::NonEmpty::try_from(VecMin::<_, 0>::try_from([1, 2].to_vec()).unwrap()),
+            Ok(nonempty::nonempty![1, 2])
+        );
+    }
+
+    #[cfg(feature = "vec1")]
+    #[test]
+    fn vec1() {
+        let v = VecOne::from(vec1::vec1![1, 2]);
+        assert_eq!(v.clone().into_vec1(), vec1::vec1![1, 2]);
+
+        assert!(vec1::Vec1::try_from(VecMin::<i32, 0>::default()).is_err());
     }
 }
@@ -0,0 +1,192 @@
+//! Kani proof harnesses for the minimum length invariant.
+//!
+//! Run with `cargo kani --features verification`. Each harness starts from an arbitrary valid vector, applies a
+//! bounded sequence of arbitrary safe operations, and checks that the length never drops below the minimum.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::VecMin;
+
+const M: usize = 2;
+const MAX_LEN: usize = 4;
+const STEPS: usize = 3;
+
+fn any_vec_min() -> VecMin<u8, M> {
+    let len: usize = kani::any();
+    kani::assume(len >= M && len <= MAX_LEN);
+
+    VecMin::try_from_vec(vec![kani::any(); len]).unwrap()
+}
+
+fn any_index(len: usize) -> usize {
+    let index: usize = kani::any();
+    kani::assume(index < len);
+    index
+}
+
+fn any_range(len: usize) -> (usize, usize) {
+    let start: usize = kani::any();
+    let end: usize = kani::any();
+    kani::assume(start <= end && end <= len);
+    (start, end)
+}
+
+fn any_len() -> usize {
+    let len: usize = kani::any();
+    kani::assume(len <= MAX_LEN + 1);
+    len
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn guarded_operations_preserve_minimum() {
+    let mut v = any_vec_min();
+
+    for _ in 0..STEPS {
+        let len = v.len();
+
+        match kani::any::<u8>() % 16 {
+            0 => drop(v.pop_to_min()),
+            1 => drop(v.pop_to_min_if(|_| kani::any())),
+            2 => drop(v.remove(any_index(len))),
+            3 => drop(v.swap_remove(any_index(len))),
+            4 => drop(v.remove_or_min(any_index(len))),
+            5 => drop(v.swap_remove_or_min(any_index(len))),
+            6 => drop(v.truncate(any_len())),
+            7 => v.truncate_or_min(any_len()),
+            8 => v.truncate_to_min(),
+            9 => drop(v.resize(any_len(), kani::any())),
+            10 => v.resize_or_min(any_len(), kani::any()),
+            11 => drop(v.split_off(any_index(len + 1))),
+            12 => drop(v.split_off_min()),
+            13 => v.shrink_to_min(),
+            14 => {
+                let (start, end) = any_range(len);
+                drop(v.drain_min::<1, _>(start..end));
+            }
+            _ => {
+                let (start, end) = any_range(len);
+                let replacement: Vec<u8> = vec![kani::any(); any_len()];
+                drop(v.replace_range(start..end, &replacement));
+            }
+        }
+
+        v.assert_invariant();
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn filtering_operations_preserve_minimum() {
+    let mut v = any_vec_min();
+
+    for _ in 0..STEPS {
+        match kani::any::<u8>() % 14 {
+            0 => drop(v.retain(|_| kani::any())),
+            1 => v.retain_to_min(|_| kani::any()),
+            2 => drop(v.swap_retain(|_| kani::any())),
+            3 => v.swap_retain_to_min(|_| kani::any()),
+            4 => drop(v.retain_drain(|_| kani::any())),
+            5 => drop(v.dedup()),
+            6 => drop(v.dedup_by_key(|x| *x / 2)),
+            7 => drop(v.dedup_by(|_, _| kani::any())),
+            8 => v.dedup_to_min(),
+            9 => v.dedup_by_key_to_min(|x| *x / 2),
+            10 => v.dedup_by_to_min(|_, _| kani::any()),
+            11 => drop(v.unique()),
+            12 => drop(v.unique_by_key(|x| *x / 2)),
+            _ => v.unique_to_min(),
+        }
+
+        v.assert_invariant();
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn drain_preserves_minimum() {
+    let mut v = any_vec_min();
+    let (start, end) = any_range(v.len());
+
+    if let Ok(drain) = v.drain(start..end) {
+        drop(drain);
+    }
+
+    v.assert_invariant();
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn leaked_drain_preserves_minimum() {
+    let mut v = any_vec_min();
+    let (start, end) = any_range(v.len());
+
+    if let Ok(drain) = v.drain(start..end) {
+        core::mem::forget(drain);
+    }
+
+    v.assert_invariant();
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn extract_if_min_preserves_minimum() {
+    let mut v = any_vec_min();
+    let (start, end) = any_range(v.len());
+
+    let mut extract = v.extract_if_min(start..end, |_| kani::any());
+    for _ in 0..any_len() {
+        drop(extract.next());
+    }
+    drop(extract);
+
+    v.assert_invariant();
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn leaked_extract_if_min_preserves_minimum() {
+    let mut v = any_vec_min();
+    let (start, end) = any_range(v.len());
+
+    let mut extract = v.extract_if_min(start..end, |_| kani::any());
+    for _ in 0..any_len() {
+        drop(extract.next());
+    }
+    core::mem::forget(extract);
+
+    v.assert_invariant();
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn relax_and_tighten_preserve_minimum() {
+    let v = any_vec_min();
+
+    let relaxed: VecMin<u8, 1> = v.relax();
+    relaxed.assert_invariant();
+
+    match relaxed.tighten::<3>() {
+        Ok(tightened) => tightened.assert_invariant(),
+        Err(relaxed) => relaxed.assert_invariant(),
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn edit_batch_preserves_minimum() {
+    let mut v = any_vec_min();
+    let len = v.len();
+    let (start, end) = any_range(len);
+
+    let replace_with: Vec<u8> = vec![kani::any(); any_len()];
+    let _ = v
+        .edit()
+        .remove(any_index(len))
+        .splice(start.min(len - 1)..end.min(len - 1), replace_with)
+        .truncate(any_len())
+        .apply();
+
+    v.assert_invariant();
+}
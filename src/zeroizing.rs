@@ -0,0 +1,114 @@
+//! Implementation of the [`ZeroizingVecMin`] newtype.
+
+use core::mem;
+use core::ops::{Deref, DerefMut};
+
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::vec::VecMin;
+
+/// A [`VecMin`] that zeroizes its elements and spare capacity when dropped.
+///
+/// `VecMin` is a type alias and cannot implement `Drop` itself, so this wraps it like
+/// [`Zeroizing`](zeroize::Zeroizing) wraps other types. As with `Zeroizing<Vec<T>>`, memory freed by a reallocation
+/// while the vector grows is not zeroized, so reserve the full capacity up front, and elements removed from the
+/// vector are not zeroized once they have left it.
+///
+/// ```
+/// use vecmin::{ZeroizingVecMin, vecmin};
+///
+/// let mut key = ZeroizingVecMin::new(vecmin![16; [0u8; 32]]);
+/// key[0] = 1;
+/// assert_eq!(key.len(), 32);
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ZeroizingVecMin<T: Zeroize, const M: usize> {
+    vec: VecMin<T, M>,
+}
+
+// --- Constructors, Convertors, and Destructors ---
+impl<T: Zeroize, const M: usize> ZeroizingVecMin<T, M> {
+    /// Wraps a `VecMin` to zeroize it on drop.
+    #[inline]
+    pub const fn new(vec: VecMin<T, M>) -> Self {
+        Self { vec }
+    }
+
+    /// Returns the inner `VecMin`, which is no longer zeroized on drop.
+    #[inline]
+    pub fn into_inner(mut self) -> VecMin<T, M> {
+        // Invariant: The `Vec` left behind is empty, but it is only zeroized and dropped along with `self`.
+        VecMin::from_inner(mem::take(&mut self.vec.inner))
+    }
+}
+
+impl<T: Zeroize, const M: usize> From<VecMin<T, M>> for ZeroizingVecMin<T, M> {
+    #[inline]
+    fn from(vec: VecMin<T, M>) -> Self {
+        Self::new(vec)
+    }
+}
+
+impl<T: Zeroize, const M: usize> Drop for ZeroizingVecMin<T, M> {
+    #[inline]
+    fn drop(&mut self) {
+        self.vec.zeroize();
+    }
+}
+
+impl<T: Zeroize, const M: usize> Zeroize for ZeroizingVecMin<T, M> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.vec.zeroize();
+    }
+}
+
+impl<T: Zeroize, const M: usize> ZeroizeOnDrop for ZeroizingVecMin<T, M> {}
+
+// --- View ---
+impl<T: Zeroize, const M: usize> Deref for ZeroizingVecMin<T, M> {
+    type Target = VecMin<T, M>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.vec
+    }
+}
+
+impl<T: Zeroize, const M: usize> DerefMut for ZeroizingVecMin<T, M> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.vec
+    }
+}
+
+impl<T: Zeroize, const M: usize> AsRef<[T]> for ZeroizingVecMin<T, M> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self.vec.as_slice()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vecmin;
+
+    #[test]
+    fn zeroizing() {
+        let mut v = ZeroizingVecMin::new(vecmin![1; [1u8, 2]]);
+        v.push(3);
+        assert_eq!(v.as_slice(), [1, 2, 3]);
+
+        let mut zeroized = v.clone();
+        zeroized.zeroize();
+        assert_eq!(zeroized.as_slice(), [0, 0, 0]);
+
+        let v = v.into_inner();
+        assert_eq!(v.as_slice(), [1, 2, 3]);
+
+        fn zeroize_on_drop<T: ZeroizeOnDrop>() {}
+        zeroize_on_drop::<ZeroizingVecMin<u8, 1>>();
+    }
+}
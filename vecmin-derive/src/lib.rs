@@ -0,0 +1,235 @@
+//! Derive macros for the `vecmin` crate, re-exported by its `derive` feature.
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, Fields, GenericParam, parse_macro_input, parse_quote};
+
+/// Generates a struct-of-arrays container with a `VecMin` column per field and a shared minimum length.
+///
+/// For a struct `Foo` with named fields, generates `FooSoaMin<const M: usize>` with the same visibility and generics
+/// plus `M`. Its columns always have the same length of at least `M`, since rows are only added and removed whole:
+///
+/// - `from_rows`, `into_rows`, `from_array` and `minimum`, `len` convert and query the container.
+/// - `push` adds a row, and `remove`, `swap_remove` and `pop_to_min` remove one, returning an error or `None` if the
+///   length would drop below `M`.
+/// - `<field>()` and `<field>_mut()` return each column as a slice, which can be mutated but not resized.
+///
+/// Fields named like one of the generated methods are rejected.
+#[proc_macro_derive(SoaMin)]
+pub fn derive_soa_min(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) if !fields.named.is_empty() => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "`SoaMin` requires a struct with at least one named field",
+                ));
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "`SoaMin` can only be derived for structs",
+            ));
+        }
+    };
+
+    const RESERVED: &[&str] = &[
+        "from_rows",
+        "from_array",
+        "into_rows",
+        "minimum",
+        "len",
+        "push",
+        "remove",
+        "swap_remove",
+        "pop_to_min",
+    ];
+    for name in fields.iter().filter_map(|field| field.ident.as_ref()) {
+        if RESERVED.iter().any(|reserved| name == reserved) {
+            return Err(Error::new_spanned(
+                name,
+                format!("`{name}` clashes with a method of the generated `SoaMin` container"),
+            ));
+        }
+    }
+
+    let vis = &input.vis;
+    let row = &input.ident;
+    let soa = format_ident!("{}SoaMin", row);
+    let (_, row_ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut generics = input.generics.clone();
+    generics
+        .params
+        .push(GenericParam::Const(parse_quote!(const M: usize)));
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    let params = &generics.params;
+
+    let names: Vec<_> = fields
+        .iter()
+        .filter_map(|field| field.ident.as_ref())
+        .collect();
+    let names_mut: Vec<_> = names
+        .iter()
+        .map(|name| format_ident!("{}_mut", name))
+        .collect();
+    let tys: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+    let first = names[0];
+
+    let column_docs = names
+        .iter()
+        .map(|name| format!("Returns the `{name}` column."));
+    let column_mut_docs = names
+        .iter()
+        .map(|name| format!("Returns the `{name}` column as a mutable slice."));
+    let soa_doc =
+        format!("A struct-of-arrays container of [`{row}`] rows with a minimum length of `M`.");
+    let columns: Vec<_> = names
+        .iter()
+        .map(|name| format_ident!("__{}", name))
+        .collect();
+    let unreachable = quote!(::core::unreachable!());
+    let vecmin = quote!(::vecmin);
+
+    Ok(quote! {
+        #[doc = #soa_doc]
+        #vis struct #soa<#params> #where_clause {
+            #(#names: #vecmin::VecMin<#tys, M>,)*
+        }
+
+        #[allow(dead_code)]
+        impl #impl_generics #soa #ty_generics #where_clause {
+            /// Splits rows into columns.
+            #vis fn from_rows(rows: #vecmin::VecMin<#row #row_ty_generics, M>) -> Self {
+                let len = rows.len();
+                #(let mut #columns = #vecmin::__alloc::vec::Vec::with_capacity(len);)*
+                for row in rows {
+                    #(#columns.push(row.#names);)*
+                }
+                Self {
+                    #(#names: match #vecmin::VecMin::try_from_vec(#columns) {
+                        ::core::result::Result::Ok(column) => column,
+                        ::core::result::Result::Err(_) => #unreachable,
+                    },)*
+                }
+            }
+
+            /// Creates the container from an array of the minimum rows.
+            #vis fn from_array(rows: [#row #row_ty_generics; M]) -> Self {
+                Self::from_rows(#vecmin::VecMin::from_array(rows))
+            }
+
+            /// Joins the columns back into rows, consuming the container.
+            #vis fn into_rows(self) -> #vecmin::VecMin<#row #row_ty_generics, M> {
+                let len = self.len();
+                #(let mut #columns = self.#names.into_iter();)*
+                let mut rows = #vecmin::__alloc::vec::Vec::with_capacity(len);
+                for _ in 0..len {
+                    rows.push(#row {
+                        #(#names: match #columns.next() {
+                            ::core::option::Option::Some(value) => value,
+                            ::core::option::Option::None => #unreachable,
+                        },)*
+                    });
+                }
+                match #vecmin::VecMin::try_from_vec(rows) {
+                    ::core::result::Result::Ok(rows) => rows,
+                    ::core::result::Result::Err(_) => #unreachable,
+                }
+            }
+
+            /// Returns the minimum number of rows.
+            #[inline]
+            #vis const fn minimum(&self) -> usize {
+                M
+            }
+
+            /// Returns the number of rows.
+            #[inline]
+            #[allow(clippy::len_without_is_empty)]
+            #vis fn len(&self) -> usize {
+                self.#first.len()
+            }
+
+            /// Adds a row to the end of every column.
+            #[inline]
+            #vis fn push(&mut self, row: #row #row_ty_generics) {
+                #(self.#names.push(row.#names);)*
+            }
+
+            /// Removes the row at `index`, returning an error if the operation would reduce the length below `M`.
+            ///
+            /// # Panics
+            /// Panics if `index` is out of bounds and the length is greater than `M`.
+            #[track_caller]
+            #[must_use = "this operation may fail"]
+            #vis fn remove(
+                &mut self,
+                index: usize,
+            ) -> ::core::result::Result<#row #row_ty_generics, #vecmin::ModifyError<M>> {
+                if self.len() <= M {
+                    return ::core::result::Result::Err(#vecmin::ModifyError);
+                }
+                ::core::result::Result::Ok(#row {
+                    #(#names: match self.#names.remove(index) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(_) => #unreachable,
+                    },)*
+                })
+            }
+
+            /// Removes the row at `index`, replacing it with the last row, returning an error if the operation would
+            /// reduce the length below `M`.
+            ///
+            /// # Panics
+            /// Panics if `index` is out of bounds and the length is greater than `M`.
+            #[track_caller]
+            #[must_use = "this operation may fail"]
+            #vis fn swap_remove(
+                &mut self,
+                index: usize,
+            ) -> ::core::result::Result<#row #row_ty_generics, #vecmin::ModifyError<M>> {
+                if self.len() <= M {
+                    return ::core::result::Result::Err(#vecmin::ModifyError);
+                }
+                ::core::result::Result::Ok(#row {
+                    #(#names: match self.#names.swap_remove(index) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(_) => #unreachable,
+                    },)*
+                })
+            }
+
+            /// Pops the last row if the length is greater than `M`, otherwise does nothing and returns `None`.
+            #[inline]
+            #vis fn pop_to_min(&mut self) -> ::core::option::Option<#row #row_ty_generics> {
+                self.remove(self.len().checked_sub(1)?).ok()
+            }
+
+            #(
+                #[doc = #column_docs]
+                #[inline]
+                #vis fn #names(&self) -> &[#tys] {
+                    self.#names.as_slice()
+                }
+            )*
+
+            #(
+                #[doc = #column_mut_docs]
+                #[inline]
+                #vis fn #names_mut(&mut self) -> &mut [#tys] {
+                    self.#names.as_mut_slice()
+                }
+            )*
+        }
+    })
+}